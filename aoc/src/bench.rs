@@ -0,0 +1,245 @@
+use crate::days::{self, DAYS, Day};
+use crate::error::Error;
+use crate::inputs::InputArgs;
+use crate::output::{self, Format, input_hash};
+use aoc_common::ParseError;
+use aoc_common::solution::{Solution, implementations};
+use clap::Args;
+use serde::Serialize;
+use std::num::ParseFloatError;
+use std::time::{Duration, Instant};
+
+#[derive(Args)]
+pub struct BenchArgs {
+    /// Day to benchmark. Benchmarks every day if omitted.
+    #[arg(long)]
+    day: Option<u8>,
+
+    /// Part to benchmark. Benchmarks both parts if omitted.
+    #[arg(long, value_parser = clap::value_parser!(u8).range(1..=2))]
+    part: Option<u8>,
+
+    #[command(flatten)]
+    input: InputArgs,
+
+    #[command(flatten)]
+    config: BenchConfig,
+
+    #[arg(long, value_enum, default_value_t)]
+    format: Format,
+}
+
+#[derive(Args, Clone)]
+pub struct BenchConfig {
+    /// Untimed runs before sampling starts. Also cut short by the budget.
+    #[arg(long, default_value_t = 3)]
+    warmup: usize,
+
+    /// Timed runs per implementation.
+    #[arg(long, default_value_t = 20)]
+    iterations: usize,
+
+    /// Stop sampling an implementation early once it has run for this many seconds.
+    #[arg(long, default_value_t = 10.0, value_parser = seconds)]
+    budget: f64,
+}
+
+/// Parses a number of seconds that can be waited for: finite, not negative and not too
+/// large for a `Duration`.
+pub fn seconds(s: &str) -> Result<f64, String> {
+    let secs: f64 = s.parse().map_err(|err: ParseFloatError| err.to_string())?;
+    Duration::try_from_secs_f64(secs)
+        .map_err(|_| "expected a finite, non-negative number of seconds".to_string())?;
+    Ok(secs)
+}
+
+/// Timing statistics for one implementation, or for parsing when `part` is `None`.
+#[derive(Serialize)]
+pub struct Measurement {
+    day: u8,
+    part: Option<u8>,
+    implementation: String,
+    answer: Option<String>,
+    samples: usize,
+    input_bytes: usize,
+    input_sha256: String,
+    median_ns: u64,
+    p95_ns: u64,
+    min_ns: u64,
+    /// Input bytes processed per second, based on the median.
+    bytes_per_sec: f64,
+    /// Median relative to the fastest implementation of the same part.
+    relative: f64,
+}
+
+pub fn bench(args: BenchArgs) -> Result<(), Error> {
+    let selected: Vec<&Day> = match args.day {
+        Some(day) => vec![days::find(day).ok_or(Error::UnknownDay(day))?],
+        None => DAYS.iter().collect(),
+    };
+
+    let mut measurements = Vec::new();
+
+    for day in selected {
+        let (path, input) = args.input.load(day)?;
+        let day_measurements =
+            (day.bench)(&input, &args.config).map_err(|err| err.with_file(&path))?;
+
+        measurements.extend(
+            day_measurements
+                .into_iter()
+                .filter(|m| args.part.is_none() || m.part.is_none() || m.part == args.part),
+        );
+    }
+
+    compare(&mut measurements);
+
+    match args.format {
+        Format::Text => print_text(&measurements),
+        Format::Json => output::print_json(&measurements),
+    }
+
+    Ok(())
+}
+
+/// Benchmarks parsing and every implementation of `S` on `input`.
+pub fn bench_solution<S: Solution>(
+    input: &str,
+    config: &BenchConfig,
+) -> Result<Vec<Measurement>, ParseError> {
+    let model = S::parse(input)?;
+    let mut measurements = vec![measure(S::DAY, None, "parse", input, config, || {
+        S::parse(input).map(|_| None)
+    })?];
+
+    for implementation in implementations::<S>() {
+        measurements.push(measure(
+            S::DAY,
+            Some(implementation.part),
+            implementation.name,
+            input,
+            config,
+            || Ok(Some((implementation.run)(&model))),
+        )?);
+    }
+
+    Ok(measurements)
+}
+
+fn measure(
+    day: u8,
+    part: Option<u8>,
+    implementation: &str,
+    input: &str,
+    config: &BenchConfig,
+    mut run: impl FnMut() -> Result<Option<String>, ParseError>,
+) -> Result<Measurement, ParseError> {
+    let budget = Duration::from_secs_f64(config.budget);
+
+    let started = Instant::now();
+    for _ in 0..config.warmup {
+        run()?;
+        if started.elapsed() > budget {
+            break;
+        }
+    }
+
+    let started = Instant::now();
+
+    let mut answer = None;
+    let mut samples = Vec::with_capacity(config.iterations);
+
+    while samples.len() < config.iterations.max(1) {
+        let start = Instant::now();
+        answer = run()?;
+        samples.push(start.elapsed());
+
+        if started.elapsed() > budget {
+            break;
+        }
+    }
+
+    samples.sort();
+    let median = percentile(&samples, 50.0);
+
+    Ok(Measurement {
+        day,
+        part,
+        implementation: implementation.to_string(),
+        answer,
+        samples: samples.len(),
+        input_bytes: input.len(),
+        input_sha256: input_hash(input),
+        median_ns: median.as_nanos() as u64,
+        p95_ns: percentile(&samples, 95.0).as_nanos() as u64,
+        min_ns: samples[0].as_nanos() as u64,
+        bytes_per_sec: input.len() as f64 / median.as_secs_f64(),
+        relative: 1.0,
+    })
+}
+
+/// Nearest-rank percentile of sorted samples.
+fn percentile(sorted: &[Duration], p: f64) -> Duration {
+    let rank = (p / 100.0 * sorted.len() as f64).ceil() as usize;
+    sorted[rank.clamp(1, sorted.len()) - 1]
+}
+
+fn compare(measurements: &mut [Measurement]) {
+    let fastest: Vec<u64> = measurements
+        .iter()
+        .map(|m| {
+            measurements
+                .iter()
+                .filter(|other| other.day == m.day && other.part == m.part)
+                .map(|other| other.median_ns)
+                .min()
+                .unwrap_or(m.median_ns)
+        })
+        .collect();
+
+    for (m, fastest) in measurements.iter_mut().zip(fastest) {
+        m.relative = m.median_ns as f64 / fastest.max(1) as f64;
+    }
+}
+
+fn print_text(measurements: &[Measurement]) {
+    let width = measurements
+        .iter()
+        .map(|m| m.implementation.len())
+        .max()
+        .unwrap_or(0)
+        .max("Implementation".len());
+
+    println!(
+        "{:>3}  {:>4}  {:<width$}  {:>7}  {:>12}  {:>12}  {:>10}  {:>8}",
+        "Day", "Part", "Implementation", "Samples", "Median", "P95", "MB/s", "Relative"
+    );
+    for m in measurements {
+        let part = m.part.map_or("-".to_string(), |p| p.to_string());
+        println!(
+            "{:>3}  {:>4}  {:<width$}  {:>7}  {:>12}  {:>12}  {:>10.2}  {:>7.2}x",
+            m.day,
+            part,
+            m.implementation,
+            m.samples,
+            format!("{:.2?}", Duration::from_nanos(m.median_ns)),
+            format!("{:.2?}", Duration::from_nanos(m.p95_ns)),
+            m.bytes_per_sec / 1e6,
+            m.relative,
+        );
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn percentile_uses_nearest_rank() {
+        let samples: Vec<Duration> = (1..=20).map(Duration::from_millis).collect();
+
+        assert_eq!(percentile(&samples, 50.0), Duration::from_millis(10));
+        assert_eq!(percentile(&samples, 95.0), Duration::from_millis(19));
+        assert_eq!(percentile(&samples[..1], 95.0), Duration::from_millis(1));
+    }
+}
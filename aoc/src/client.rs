@@ -0,0 +1,105 @@
+use crate::error::Error;
+use clap::Args;
+use std::time::Duration;
+use ureq::Agent;
+
+const USER_AGENT: &str = concat!("aoc-24/", env!("CARGO_PKG_VERSION"));
+
+/// Where to reach the puzzle server and how to authenticate with it.
+#[derive(Args, Clone)]
+pub struct ServerArgs {
+    /// Base URL of the puzzle server.
+    #[arg(long, env = "AOC_BASE_URL", default_value = "https://adventofcode.com")]
+    pub base_url: String,
+
+    /// Session cookie used to authenticate with the puzzle server.
+    #[arg(long, env = "AOC_SESSION", hide_env_values = true)]
+    pub session: Option<String>,
+
+    /// Puzzle year.
+    #[arg(long, default_value_t = 2024)]
+    pub year: u16,
+}
+
+/// A client for the puzzle server.
+pub struct Client {
+    base_url: String,
+    session: Option<String>,
+    agent: Agent,
+}
+
+impl Client {
+    pub fn new(args: &ServerArgs) -> Self {
+        let agent = Agent::config_builder()
+            .http_status_as_error(false)
+            .timeout_global(Some(Duration::from_secs(30)))
+            .build()
+            .into();
+
+        Self {
+            base_url: args.base_url.trim_end_matches('/').to_string(),
+            session: args.session.clone(),
+            agent,
+        }
+    }
+
+    /// Downloads the puzzle input for a day.
+    pub fn input(&self, year: u16, day: u8) -> Result<String, Error> {
+        let url = format!("{}/{year}/day/{day}/input", self.base_url);
+
+        let response = self
+            .agent
+            .get(&url)
+            .header("Cookie", self.cookie()?)
+            .header("User-Agent", USER_AGENT)
+            .call();
+
+        read_body(&url, response)
+    }
+
+    /// Posts an answer for one part of a day, returning the server's HTML reply.
+    pub fn submit(&self, year: u16, day: u8, part: u8, answer: &str) -> Result<String, Error> {
+        let url = format!("{}/{year}/day/{day}/answer", self.base_url);
+
+        let response = self
+            .agent
+            .post(&url)
+            .header("Cookie", self.cookie()?)
+            .header("User-Agent", USER_AGENT)
+            .send_form([("level", part.to_string().as_str()), ("answer", answer)]);
+
+        read_body(&url, response)
+    }
+
+    fn cookie(&self) -> Result<String, Error> {
+        match &self.session {
+            Some(session) => Ok(format!("session={session}")),
+            None => Err(Error::MissingSession),
+        }
+    }
+}
+
+/// Reads a successful response's body, turning transport failures and error statuses into
+/// errors.
+fn read_body(
+    url: &str,
+    response: Result<ureq::http::Response<ureq::Body>, ureq::Error>,
+) -> Result<String, Error> {
+    let http_error = |err: ureq::Error| Error::Http {
+        url: url.to_string(),
+        message: err.to_string(),
+    };
+
+    let mut response = response.map_err(http_error)?;
+    let status = response.status().as_u16();
+    let body = response.body_mut().read_to_string().map_err(http_error)?;
+
+    if !(200..300).contains(&status) {
+        return Err(Error::Http {
+            url: url.to_string(),
+            message: format!("server responded with {status}: {}", body.trim()),
+        });
+    }
+
+    Ok(body)
+}
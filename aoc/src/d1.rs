@@ -0,0 +1,249 @@
+use crate::error::Error;
+use crate::inputs::InputFile;
+use crate::output::print_json;
+use aoc_common::Solution;
+use clap::builder::{PossibleValue, PossibleValuesParser};
+use clap::{Args, Subcommand, ValueEnum};
+use d1::explain::explain;
+use d1::external::{self, ExternalSort};
+use d1::incremental::{self, Engine};
+use d1::metric::{self, METRICS};
+use d1::{Day1, lists_similarity, matrix, read_columns};
+use std::fs;
+use std::io;
+use std::path::PathBuf;
+
+#[derive(Args)]
+pub struct D1Args {
+    #[command(subcommand)]
+    command: D1Command,
+}
+
+#[derive(Subcommand)]
+enum D1Command {
+    /// Work out both answers for lists too large to fit in memory, sorting them on disk
+    External(ExternalArgs),
+    /// Measure how far apart the two lists are
+    Distance(DistanceArgs),
+    /// Break both answers down pair by pair
+    Explain(ExplainArgs),
+    /// Compare every column of an input with any number of columns against every other
+    Matrix(MatrixArgs),
+    /// Keep both answers up to date as pairs are added and removed by commands on stdin
+    Serve(ServeArgs),
+}
+
+#[derive(Args)]
+struct ExternalArgs {
+    #[command(flatten)]
+    input: InputFile,
+
+    /// Numbers of each list to sort in memory before spilling them to disk.
+    #[arg(long, default_value_t = ExternalSort::default().run_len)]
+    run_len: usize,
+
+    /// Most sorted runs to merge at once.
+    #[arg(long, default_value_t = ExternalSort::default().fan_in)]
+    fan_in: usize,
+
+    /// Directory to spill sorted runs to. Defaults to the system's temporary directory.
+    #[arg(long)]
+    temp_dir: Option<PathBuf>,
+}
+
+#[derive(Args)]
+struct DistanceArgs {
+    #[command(flatten)]
+    input: InputFile,
+
+    /// Metric to measure with. Can be given more than once.
+    #[arg(long, default_value = "l1", value_parser = metric_names())]
+    metric: Vec<String>,
+
+    /// Measure with every metric.
+    #[arg(long, conflicts_with = "metric")]
+    all: bool,
+}
+
+#[derive(Args)]
+struct ExplainArgs {
+    #[command(flatten)]
+    input: InputFile,
+
+    #[arg(long, value_enum, default_value_t)]
+    format: ExplainFormat,
+}
+
+#[derive(Clone, Copy, Default, ValueEnum)]
+enum ExplainFormat {
+    #[default]
+    Csv,
+    Json,
+}
+
+#[derive(Args)]
+struct MatrixArgs {
+    #[command(flatten)]
+    input: InputFile,
+
+    /// Metric to measure distances with.
+    #[arg(long, default_value = "l1", value_parser = metric_names())]
+    metric: String,
+}
+
+#[derive(Args)]
+struct ServeArgs {
+    /// Start from the lists in this file instead of empty ones. It's always opened as a
+    /// file, since stdin carries the commands.
+    #[arg(long)]
+    load: Option<PathBuf>,
+}
+
+fn metric_names() -> PossibleValuesParser {
+    PossibleValuesParser::new(
+        METRICS
+            .iter()
+            .map(|m| PossibleValue::new(m.name).help(m.description)),
+    )
+}
+
+pub fn d1(args: D1Args) -> Result<(), Error> {
+    match args.command {
+        D1Command::External(args) => external(args),
+        D1Command::Distance(args) => distance(args),
+        D1Command::Explain(args) => explain_pairs(args),
+        D1Command::Matrix(args) => distance_matrix(args),
+        D1Command::Serve(args) => serve(args),
+    }
+}
+
+fn external(args: ExternalArgs) -> Result<(), Error> {
+    let config = ExternalSort {
+        run_len: args.run_len,
+        fan_in: args.fan_in,
+        temp_dir: args.temp_dir,
+    };
+
+    let (path, reader) = args.input.open(1)?;
+    let totals = config.totals(reader).map_err(|err| match err {
+        external::Error::Io(source) => Error::Io { path, source },
+        external::Error::Parse(err) => Error::Parse(err.with_file(path)),
+        external::Error::Overflow => Error::Overflow(1),
+    })?;
+
+    println!("part 1: {}", totals.distance);
+    println!("part 2: {}", totals.similarity);
+
+    Ok(())
+}
+
+/// Reads and parses the whole input, giving both lists sorted.
+fn load_lists(input: &InputFile) -> Result<(Vec<i64>, Vec<i64>), Error> {
+    let (path, input) = input.read(1)?;
+    Ok(Day1::parse(&input).map_err(|err| err.with_file(&path))?)
+}
+
+fn distance(args: DistanceArgs) -> Result<(), Error> {
+    let (list1, list2) = load_lists(&args.input)?;
+
+    let metrics: Vec<_> = match args.all {
+        true => METRICS.iter().collect(),
+        false => args
+            .metric
+            .iter()
+            .map(|name| metric::find(name).expect("clap only accepts known metrics"))
+            .collect(),
+    };
+    for metric in metrics {
+        println!("{}: {}", metric.name, (metric.distance)(&list1, &list2));
+    }
+
+    Ok(())
+}
+
+fn explain_pairs(args: ExplainArgs) -> Result<(), Error> {
+    let (list1, list2) = load_lists(&args.input)?;
+    let pairs = explain(&list1, &list2).ok_or(Error::Overflow(1))?;
+
+    match args.format {
+        ExplainFormat::Json => print_json(pairs),
+        ExplainFormat::Csv => {
+            println!("rank,left,right,distance,occurrences,similarity");
+            for pair in pairs {
+                println!(
+                    "{},{},{},{},{},{}",
+                    pair.rank,
+                    pair.left,
+                    pair.right,
+                    pair.distance,
+                    pair.occurrences,
+                    pair.similarity
+                );
+            }
+        }
+    }
+
+    Ok(())
+}
+
+fn distance_matrix(args: MatrixArgs) -> Result<(), Error> {
+    let (path, input) = args.input.read(1)?;
+    let columns = read_columns(&input).map_err(|err| err.with_file(&path))?;
+    let metric = metric::find(&args.metric).expect("clap only accepts known metrics");
+
+    let distances = matrix(&columns, metric.distance);
+    print_matrix(&format!("distance ({})", metric.name), &distances);
+    println!();
+
+    let similarities = matrix(&columns, lists_similarity::<i64>);
+    let similarities: Option<Vec<Vec<i64>>> = similarities
+        .into_iter()
+        .map(|row| row.into_iter().collect())
+        .collect();
+    print_matrix("similarity", &similarities.ok_or(Error::Overflow(1))?);
+
+    Ok(())
+}
+
+/// Prints a square matrix with its columns numbered from 1 like the input's.
+fn print_matrix(title: &str, rows: &[Vec<impl ToString>]) {
+    let cells: Vec<Vec<String>> = rows
+        .iter()
+        .map(|row| row.iter().map(ToString::to_string).collect())
+        .collect();
+    let width = cells
+        .iter()
+        .flatten()
+        .map(String::len)
+        .chain([rows.len().to_string().len()])
+        .max()
+        .unwrap_or(0);
+    let label = rows.len().to_string().len();
+
+    println!("{title}");
+    let header: String = (1..=rows.len()).map(|i| format!("  {i:>width$}")).collect();
+    println!("{:label$}{header}", "");
+    for (i, row) in cells.iter().enumerate() {
+        let row: String = row.iter().map(|cell| format!("  {cell:>width$}")).collect();
+        println!("{:>label$}{row}", i + 1);
+    }
+}
+
+fn serve(args: ServeArgs) -> Result<(), Error> {
+    let mut engine = Engine::new();
+    if let Some(path) = args.load {
+        let input = fs::read_to_string(&path).map_err(|source| Error::Io {
+            path: path.clone(),
+            source,
+        })?;
+        let (list1, list2) = Day1::parse(&input).map_err(|err| err.with_file(&path))?;
+        engine.extend(list1.into_iter().zip(list2));
+    }
+
+    incremental::serve(&mut engine, io::stdin().lock(), io::stdout().lock()).map_err(|source| {
+        Error::Io {
+            path: PathBuf::from("<stdio>"),
+            source,
+        }
+    })
+}
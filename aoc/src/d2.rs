@@ -0,0 +1,261 @@
+use crate::error::Error;
+use crate::inputs::InputFile;
+use crate::output::print_json;
+use clap::builder::{PossibleValuesParser, TypedValueParser};
+use clap::{Args, Subcommand, ValueEnum};
+use d2::diagnose::diagnose;
+use d2::policy::{Direction, Trend};
+use d2::stream::{self, Outcome, check_reports};
+use d2::{InputMode, Reports, SafetyPolicy, get_num_safe, num_safe_dropout, read_reports};
+use std::fs;
+use std::io::{self, Write};
+use std::path::PathBuf;
+
+#[derive(Args)]
+pub struct D2Args {
+    #[command(subcommand)]
+    command: D2Command,
+}
+
+#[derive(Subcommand)]
+enum D2Command {
+    /// Count the safe reports under a safety policy other than the puzzle's
+    Check(CheckArgs),
+    /// Explain why each unsafe report is unsafe, and which removal would save it
+    Diagnose(DiagnoseArgs),
+    /// Check reports one line at a time as they arrive, such as a log piped to stdin
+    Stream(StreamArgs),
+}
+
+#[derive(Args)]
+struct CheckArgs {
+    #[command(flatten)]
+    input: InputArgs,
+
+    /// Most levels the Problem Dampener may remove from a report.
+    #[arg(long, default_value_t = 1)]
+    dampen: usize,
+
+    #[command(flatten)]
+    policy: PolicyArgs,
+}
+
+#[derive(Args)]
+struct DiagnoseArgs {
+    #[command(flatten)]
+    input: InputArgs,
+
+    #[arg(long, value_enum, default_value_t)]
+    format: DiagnoseFormat,
+
+    #[command(flatten)]
+    policy: PolicyArgs,
+}
+
+#[derive(Clone, Copy, Default, ValueEnum)]
+enum DiagnoseFormat {
+    #[default]
+    Table,
+    Json,
+}
+
+#[derive(Args)]
+struct StreamArgs {
+    #[command(flatten)]
+    input: InputArgs,
+
+    /// Most levels the Problem Dampener may remove from a report.
+    #[arg(long, default_value_t = 1)]
+    dampen: usize,
+
+    /// Print whether each report is safe as soon as it is read.
+    #[arg(long)]
+    verdicts: bool,
+
+    #[command(flatten)]
+    policy: PolicyArgs,
+}
+
+/// Where to read reports from, and how carefully.
+#[derive(Args)]
+struct InputArgs {
+    #[command(flatten)]
+    file: InputFile,
+
+    /// Skip lines that aren't reports instead of failing, and list them on stderr.
+    #[arg(long)]
+    lenient: bool,
+}
+
+impl InputArgs {
+    fn load(&self) -> Result<Reports, Error> {
+        let (path, input) = self.file.read(2)?;
+        let mut read = read_reports(&input, self.mode()).map_err(|err| err.with_file(&path))?;
+
+        if !read.skipped.is_empty() {
+            eprintln!("skipped {} lines:", read.skipped.len());
+            for err in read.skipped.drain(..) {
+                eprintln!("  {}", err.with_file(&path));
+            }
+        }
+
+        Ok(read)
+    }
+
+    fn mode(&self) -> InputMode {
+        match self.lenient {
+            true => InputMode::Lenient,
+            false => InputMode::Strict,
+        }
+    }
+}
+
+/// The safety policy, from a file, flags, or both. Anything not given is the puzzle's.
+#[derive(Args)]
+struct PolicyArgs {
+    /// TOML file with the policy, using the same names as the flags. Flags take precedence.
+    #[arg(long)]
+    policy: Option<PathBuf>,
+
+    /// Smallest change allowed between adjacent levels, other than a plateau.
+    #[arg(long)]
+    min_step: Option<u32>,
+
+    /// Largest change allowed between adjacent levels.
+    #[arg(long)]
+    max_step: Option<u32>,
+
+    /// Which way the levels of a safe report may go.
+    #[arg(long, value_parser = PossibleValuesParser::new(Direction::NAMES)
+        .map(|name| name.parse::<Direction>().expect("names parse")))]
+    direction: Option<Direction>,
+
+    /// Whether adjacent levels may be equal.
+    #[arg(long)]
+    plateaus: Option<bool>,
+
+    /// How to tell which way a report goes when either way is allowed.
+    #[arg(long, value_parser = PossibleValuesParser::new(Trend::NAMES)
+        .map(|name| name.parse::<Trend>().expect("names parse")))]
+    trend: Option<Trend>,
+}
+
+impl PolicyArgs {
+    fn load(&self) -> Result<SafetyPolicy, Error> {
+        let mut policy = match &self.policy {
+            Some(path) => {
+                let text = fs::read_to_string(path).map_err(|source| Error::Io {
+                    path: path.clone(),
+                    source,
+                })?;
+                SafetyPolicy::from_toml(&text)
+                    .map_err(|err| Error::Policy(format!("{}: {err}", path.display())))?
+            }
+            None => SafetyPolicy::default(),
+        };
+
+        policy.min_step = self.min_step.unwrap_or(policy.min_step);
+        policy.max_step = self.max_step.unwrap_or(policy.max_step);
+        policy.direction = self.direction.unwrap_or(policy.direction);
+        policy.plateaus = self.plateaus.unwrap_or(policy.plateaus);
+        policy.trend = self.trend.unwrap_or(policy.trend);
+
+        policy
+            .validate()
+            .map_err(|err| Error::Policy(err.to_string()))
+    }
+}
+
+pub fn d2(args: D2Args) -> Result<(), Error> {
+    match args.command {
+        D2Command::Check(args) => check(args),
+        D2Command::Diagnose(args) => diagnose_reports(args),
+        D2Command::Stream(args) => stream(args),
+    }
+}
+
+fn check(args: CheckArgs) -> Result<(), Error> {
+    let policy = args.policy.load()?;
+    let reports = args.input.load()?.reports;
+
+    println!("safe: {}", get_num_safe(&reports, &policy));
+    println!(
+        "safe with up to {} levels removed: {}",
+        args.dampen,
+        num_safe_dropout(&reports, &policy, args.dampen)
+    );
+
+    Ok(())
+}
+
+fn diagnose_reports(args: DiagnoseArgs) -> Result<(), Error> {
+    let policy = args.policy.load()?;
+    let read = args.input.load()?;
+    let diagnoses = diagnose(&read, &policy);
+
+    match args.format {
+        DiagnoseFormat::Json => print_json(diagnoses),
+        DiagnoseFormat::Table => {
+            println!(
+                "{:>6}  {:<14}  {:>5}  {:>11}  rescued by",
+                "report", "reason", "index", "values"
+            );
+            for d in diagnoses {
+                let values = format!("{} -> {}", d.values[0], d.values[1]);
+                let rescued_by = match d.rescued_by {
+                    Some(index) => format!("removing {index}"),
+                    None => "-".to_string(),
+                };
+                println!(
+                    "{:>6}  {:<14}  {:>5}  {values:>11}  {rescued_by}",
+                    d.report,
+                    d.reason.to_string(),
+                    d.index
+                );
+            }
+        }
+    }
+
+    Ok(())
+}
+
+fn stream(args: StreamArgs) -> Result<(), Error> {
+    let policy = args.policy.load()?;
+    let (path, reader) = args.input.file.open(2)?;
+    let mut stdout = io::stdout().lock();
+
+    let on_outcome = |outcome: &Outcome| match outcome {
+        Outcome::Report { line, safety } if args.verdicts => {
+            writeln!(stdout, "{line} {safety}")?;
+            stdout.flush()
+        }
+        Outcome::Report { .. } => Ok(()),
+        Outcome::Skipped(err) => {
+            eprintln!("skipped {}", err.clone().with_file(&path));
+            Ok(())
+        }
+    };
+    let tally = check_reports(reader, &policy, args.dampen, args.input.mode(), on_outcome)
+        .map_err(|err| match err {
+            stream::Error::Io(source) => Error::Io {
+                path: path.clone(),
+                source,
+            },
+            stream::Error::Parse(err) => Error::Parse(err.with_file(&path)),
+            stream::Error::Output(source) => Error::Io {
+                path: PathBuf::from("<stdout>"),
+                source,
+            },
+        })?;
+
+    println!("safe: {}", tally.safe);
+    println!(
+        "safe with up to {} levels removed: {}",
+        args.dampen, tally.dampened
+    );
+    if tally.skipped > 0 {
+        println!("skipped: {}", tally.skipped);
+    }
+
+    Ok(())
+}
@@ -0,0 +1,61 @@
+use crate::bench::{BenchConfig, Measurement, bench_solution};
+use aoc_common::ParseError;
+use aoc_common::differential::{Counterexample, search};
+use aoc_common::generate::Generated;
+use aoc_common::render::Frame;
+use aoc_common::solution::{Solution, generate, solve, visualize};
+use std::ops::Range;
+
+/// A puzzle day as seen by the runner.
+pub struct Day {
+    pub day: u8,
+    /// Default input file, relative to the workspace root.
+    pub input: &'static str,
+    /// Solves part 1, giving `None` if the answer overflows.
+    pub part_1: fn(&str) -> Result<Option<String>, ParseError>,
+    pub part_2: fn(&str) -> Result<Option<String>, ParseError>,
+    pub bench: fn(&str, &BenchConfig) -> Result<Vec<Measurement>, ParseError>,
+    pub visualize: fn(&str, u8) -> Result<Option<Frame>, ParseError>,
+    pub generate: fn(u64, f64) -> Option<Generated>,
+    pub differential: fn(Range<u64>, f64) -> Option<Counterexample>,
+}
+
+impl Day {
+    const fn new<S: Solution>(input: &'static str) -> Self {
+        Day {
+            day: S::DAY,
+            input,
+            part_1: |input| solve::<S>(input, 1),
+            part_2: |input| solve::<S>(input, 2),
+            bench: bench_solution::<S>,
+            visualize: visualize::<S>,
+            generate: generate::<S>,
+            differential: search::<S>,
+        }
+    }
+
+    pub fn part(&self, part: u8) -> fn(&str) -> Result<Option<String>, ParseError> {
+        match part {
+            1 => self.part_1,
+            2 => self.part_2,
+            _ => panic!("invalid part {part}"),
+        }
+    }
+}
+
+pub const DAYS: &[Day] = &[
+    Day::new::<d1::Day1>("d1/data/data.txt"),
+    Day::new::<d2::Day2>("d2/data.txt"),
+    Day::new::<d3::Day3>("d3/data.txt"),
+    Day::new::<d4::Day4>("d4/data.txt"),
+    Day::new::<d5::Day5>("d5/data.txt"),
+    Day::new::<d6::Day6>("d6/data.txt"),
+    Day::new::<d7::Day7>("d7/data.txt"),
+    Day::new::<d8::Day8>("d8/data.txt"),
+    Day::new::<d9::Day9>("d9/data.txt"),
+    Day::new::<d10::Day10>("d10/data.txt"),
+];
+
+pub fn find(day: u8) -> Option<&'static Day> {
+    DAYS.iter().find(|d| d.day == day)
+}
@@ -0,0 +1,86 @@
+use crate::days;
+use crate::error::Error;
+use crate::generate::scale;
+use crate::inputs::workspace_root;
+use aoc_common::differential::save_regression;
+use clap::Args;
+use std::panic;
+
+#[derive(Args)]
+pub struct DiffArgs {
+    /// Day whose implementations to compare.
+    #[arg(long)]
+    day: u8,
+
+    /// Number of generated inputs to compare on.
+    #[arg(long, default_value_t = 100)]
+    seeds: u64,
+
+    /// First seed to generate an input from.
+    #[arg(long, default_value_t = 0)]
+    start: u64,
+
+    /// Size of the inputs relative to the real one. Small inputs shrink faster.
+    #[arg(long, default_value_t = 0.1, value_parser = scale)]
+    scale: f64,
+
+    /// Save the shrunk input as a regression fixture of the day, so its tests keep
+    /// checking it.
+    #[arg(long)]
+    save: bool,
+}
+
+pub fn diff(args: DiffArgs) -> Result<(), Error> {
+    let day = days::find(args.day).ok_or(Error::UnknownDay(args.day))?;
+    if (day.generate)(args.start, args.scale).is_none() {
+        return Err(Error::NoGenerator(day.day));
+    }
+
+    // Panicking implementations count as disagreeing, and shrinking makes them panic over
+    // and over, so keep their messages out of the output
+    let hook = panic::take_hook();
+    panic::set_hook(Box::new(|_| {}));
+    let counterexample = (day.differential)(args.start..args.start + args.seeds, args.scale);
+    panic::set_hook(hook);
+
+    let Some(counterexample) = counterexample else {
+        println!(
+            "day {}: implementations agree on {} inputs",
+            day.day, args.seeds
+        );
+        return Ok(());
+    };
+
+    let disagreement = &counterexample.disagreement;
+    println!(
+        "day {} part {}, seed {} shrinks to:",
+        day.day, disagreement.part, counterexample.seed
+    );
+    println!("{}", counterexample.input.trim_end());
+    for (name, answer) in &disagreement.answers {
+        match answer {
+            Ok(answer) => println!("  {name}: {answer}"),
+            Err(message) => println!("  {name}: {message}"),
+        }
+    }
+
+    if args.save {
+        let crate_dir = workspace_root().join(format!("d{}", day.day));
+        let path =
+            save_regression(&crate_dir, &counterexample.input).map_err(|source| Error::Io {
+                path: crate_dir.clone(),
+                source,
+            })?;
+        println!(
+            "saved {}",
+            path.strip_prefix(workspace_root())
+                .unwrap_or(&path)
+                .display()
+        );
+    }
+
+    Err(Error::Disagreement {
+        day: day.day,
+        seed: counterexample.seed,
+    })
+}
@@ -0,0 +1,93 @@
+use aoc_common::ParseError;
+use std::fmt::{self, Display, Formatter};
+use std::io;
+use std::path::PathBuf;
+
+/// Everything that can make a runner command fail.
+#[derive(Debug)]
+pub enum Error {
+    UnknownDay(u8),
+    Io {
+        path: PathBuf,
+        source: io::Error,
+    },
+    Parse(ParseError),
+    Http {
+        url: String,
+        message: String,
+    },
+    MissingSession,
+    NoCacheDir,
+    /// A submission that the answer history already shows to be pointless.
+    Refused(String),
+    /// A new day's crate could not be created.
+    Scaffold(String),
+    NoGenerator(u8),
+    /// Solving a generated input gave answers other than the expected ones.
+    WrongAnswers(usize),
+    /// Implementations of a day answered a generated input differently.
+    Disagreement {
+        day: u8,
+        seed: u64,
+    },
+    /// An answer too large for the integers a day works with.
+    Overflow(u8),
+    /// A safety policy for day 2 that can't be used.
+    Policy(String),
+}
+
+impl Display for Error {
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        match self {
+            Error::UnknownDay(day) => write!(f, "day {day} is not solved yet"),
+            Error::Io { path, source } => write!(f, "{}: {source}", path.display()),
+            Error::Parse(err) => write!(f, "{err}"),
+            Error::Http { url, message } => write!(f, "{url}: {message}"),
+            Error::MissingSession => write!(
+                f,
+                "no session token, set AOC_SESSION or pass --session to talk to the puzzle server"
+            ),
+            Error::NoCacheDir => write!(
+                f,
+                "could not determine a cache directory, set AOC_CACHE_DIR or pass --cache-dir"
+            ),
+            Error::Refused(reason) => write!(f, "not submitting: {reason}"),
+            Error::Scaffold(message) => write!(f, "{message}"),
+            Error::NoGenerator(day) => write!(f, "day {day} has no input generator"),
+            Error::WrongAnswers(count) => {
+                write!(f, "{count} answer(s) differ from the expected ones")
+            }
+            Error::Disagreement { day, seed } => {
+                write!(f, "implementations of day {day} disagree on seed {seed}")
+            }
+            Error::Overflow(day) => write!(f, "an answer for day {day} overflows"),
+            Error::Policy(message) => write!(f, "invalid safety policy: {message}"),
+        }
+    }
+}
+
+impl std::error::Error for Error {
+    fn source(&self) -> Option<&(dyn std::error::Error + 'static)> {
+        match self {
+            Error::Io { source, .. } => Some(source),
+            Error::Parse(err) => Some(err),
+            Error::UnknownDay(_)
+            | Error::Http { .. }
+            | Error::MissingSession
+            | Error::NoCacheDir
+            | Error::Refused(_)
+            | Error::Scaffold(_)
+            | Error::NoGenerator(_)
+            | Error::WrongAnswers(_)
+            | Error::Disagreement { .. }
+            | Error::Overflow(_)
+            | Error::Policy(_) => None,
+        }
+    }
+}
+
+impl From<ParseError> for Error {
+    fn from(err: ParseError) -> Self {
+        Error::Parse(err)
+    }
+}
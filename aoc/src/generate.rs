@@ -0,0 +1,87 @@
+use crate::days;
+use crate::error::Error;
+use clap::Args;
+use std::fs;
+use std::io::{self, Write};
+use std::num::ParseFloatError;
+use std::path::PathBuf;
+
+#[derive(Args)]
+pub struct GenerateArgs {
+    /// Day to generate an input for.
+    #[arg(long)]
+    day: u8,
+
+    /// Seed for the random generator. The same seed and scale always give the same input.
+    #[arg(long, default_value_t = 0)]
+    seed: u64,
+
+    /// Size of the input relative to the real one.
+    #[arg(long, default_value_t = 1.0, value_parser = scale)]
+    scale: f64,
+
+    /// Write the input to this file instead of stdout.
+    #[arg(long)]
+    output: Option<PathBuf>,
+
+    /// Also solve the input, checking the answers against the expected ones.
+    #[arg(long)]
+    check: bool,
+}
+
+/// Largest `--scale` accepted, which already makes inputs far larger than the real ones.
+const MAX_SCALE: f64 = 1000.0;
+
+/// Parses a scale inputs can be generated at: positive and at most [`MAX_SCALE`].
+pub fn scale(s: &str) -> Result<f64, String> {
+    let scale: f64 = s.parse().map_err(|err: ParseFloatError| err.to_string())?;
+    match scale > 0.0 && scale <= MAX_SCALE {
+        true => Ok(scale),
+        false => Err(format!("expected a scale above 0 and at most {MAX_SCALE}")),
+    }
+}
+
+pub fn generate(args: GenerateArgs) -> Result<(), Error> {
+    let day = days::find(args.day).ok_or(Error::UnknownDay(args.day))?;
+    let generated = (day.generate)(args.seed, args.scale).ok_or(Error::NoGenerator(day.day))?;
+
+    match &args.output {
+        Some(path) => fs::write(path, &generated.input).map_err(|source| Error::Io {
+            path: path.clone(),
+            source,
+        })?,
+        None => io::stdout()
+            .write_all(generated.input.as_bytes())
+            .map_err(|source| Error::Io {
+                path: PathBuf::from("<stdout>"),
+                source,
+            })?,
+    }
+
+    // Answers go to stderr so that stdout is just the input
+    let mut mismatches = 0;
+    for part in 1..=2 {
+        let expected = generated.answer(part);
+        if !args.check {
+            if let Some(expected) = expected {
+                eprintln!("part {part}: {expected}");
+            }
+            continue;
+        }
+
+        let answer = day.part(part)(&generated.input)?.ok_or(Error::Overflow(day.day))?;
+        match expected {
+            Some(expected) if expected != answer => {
+                eprintln!("part {part}: {answer}, expected {expected}");
+                mismatches += 1;
+            }
+            Some(_) => eprintln!("part {part}: {answer}, ok"),
+            None => eprintln!("part {part}: {answer}"),
+        }
+    }
+
+    match mismatches {
+        0 => Ok(()),
+        _ => Err(Error::WrongAnswers(mismatches)),
+    }
+}
@@ -0,0 +1,328 @@
+use crate::client::{Client, ServerArgs};
+use crate::days::{self, Day};
+use crate::error::Error;
+use aoc_common::input::read_input;
+use clap::Args;
+use std::fs::{self, File};
+use std::io::{self, BufRead, BufReader};
+use std::path::{Path, PathBuf};
+
+/// Where a command reads a day's input from.
+#[derive(Args)]
+pub struct InputArgs {
+    /// Input file, or `-` to read from stdin. Defaults to the day's `data.txt`.
+    #[arg(long, requires = "day")]
+    input: Option<String>,
+
+    /// Use the input from the puzzle server, downloading it into the cache if needed,
+    /// instead of the one committed to the repository.
+    #[arg(long, conflicts_with = "input")]
+    fetch: bool,
+
+    #[command(flatten)]
+    pub cache: CacheArgs,
+
+    #[command(flatten)]
+    pub server: ServerArgs,
+}
+
+impl InputArgs {
+    /// Reads the day's input, returning the path it came from alongside its contents.
+    pub fn load(&self, day: &Day) -> Result<(PathBuf, String), Error> {
+        if self.fetch {
+            let cache = InputCache::from_args(&self.cache)?;
+            let client = Client::new(&self.server);
+            let input = fetch_input(&client, &cache, self.server.year, day.day, false)?;
+
+            return Ok((cache.path(self.server.year, day.day), input));
+        }
+
+        let path = match self.input.as_deref() {
+            Some("-") => PathBuf::from("<stdin>"),
+            Some(path) => PathBuf::from(path),
+            None => workspace_root().join(day.input),
+        };
+
+        let contents = if self.input.as_deref() == Some("-") {
+            io::read_to_string(io::stdin())
+        } else {
+            read_input(&path)
+        };
+
+        match contents {
+            Ok(contents) => Ok((path, contents)),
+            Err(source) => Err(Error::Io { path, source }),
+        }
+    }
+}
+
+/// Where a day's own subcommands read their input from: a file or stdin, never the
+/// puzzle server.
+#[derive(Args)]
+pub struct InputFile {
+    /// Input file, or `-` to read from stdin. Defaults to the day's `data.txt`.
+    #[arg(long)]
+    pub input: Option<String>,
+}
+
+impl InputFile {
+    /// Opens the input for reading as it goes, like [`open`].
+    pub fn open(&self, day: u8) -> Result<(PathBuf, Box<dyn BufRead>), Error> {
+        open(day, self.input.as_deref())
+    }
+
+    /// Reads the whole input, returning the path it came from alongside its contents.
+    pub fn read(&self, day: u8) -> Result<(PathBuf, String), Error> {
+        let (path, reader) = self.open(day)?;
+        match io::read_to_string(reader) {
+            Ok(contents) => Ok((path, contents)),
+            Err(source) => Err(Error::Io { path, source }),
+        }
+    }
+}
+
+/// Opens a day's input for reading as it goes, for commands working on inputs too large to
+/// read whole. Defaults to the day's `data.txt`, with `-` standing for stdin.
+pub fn open(day: u8, input: Option<&str>) -> Result<(PathBuf, Box<dyn BufRead>), Error> {
+    if input == Some("-") {
+        return Ok((PathBuf::from("<stdin>"), Box::new(io::stdin().lock())));
+    }
+
+    let path = match input {
+        Some(path) => PathBuf::from(path),
+        None => workspace_root().join(days::find(day).ok_or(Error::UnknownDay(day))?.input),
+    };
+    match File::open(&path) {
+        Ok(file) => Ok((path, Box::new(BufReader::new(file)))),
+        Err(source) => Err(Error::Io { path, source }),
+    }
+}
+
+pub fn workspace_root() -> &'static Path {
+    Path::new(env!("CARGO_MANIFEST_DIR"))
+        .parent()
+        .expect("the runner lives inside the workspace")
+}
+
+#[derive(Args)]
+pub struct FetchArgs {
+    /// Day to fetch.
+    #[arg(long, value_parser = clap::value_parser!(u8).range(1..=25))]
+    day: u8,
+
+    /// Download the input again even if it is already cached.
+    #[arg(long)]
+    refresh: bool,
+
+    #[command(flatten)]
+    cache: CacheArgs,
+
+    #[command(flatten)]
+    server: ServerArgs,
+}
+
+#[derive(Args, Clone)]
+pub struct CacheArgs {
+    /// Directory inputs are cached in. Defaults to a per-user cache directory.
+    #[arg(long, env = "AOC_CACHE_DIR")]
+    pub cache_dir: Option<PathBuf>,
+}
+
+impl CacheArgs {
+    pub fn dir(&self) -> Result<PathBuf, Error> {
+        self.cache_dir
+            .clone()
+            .or_else(default_cache_dir)
+            .ok_or(Error::NoCacheDir)
+    }
+}
+
+/// Downloaded inputs, stored as `<dir>/<year>/<day>.txt`.
+pub struct InputCache {
+    dir: PathBuf,
+}
+
+impl InputCache {
+    pub fn new(dir: impl Into<PathBuf>) -> Self {
+        Self { dir: dir.into() }
+    }
+
+    pub fn from_args(args: &CacheArgs) -> Result<Self, Error> {
+        args.dir().map(Self::new)
+    }
+
+    pub fn path(&self, year: u16, day: u8) -> PathBuf {
+        self.dir
+            .join(year.to_string())
+            .join(format!("{day:02}.txt"))
+    }
+
+    pub fn get(&self, year: u16, day: u8) -> Result<Option<String>, Error> {
+        let path = self.path(year, day);
+
+        match fs::read_to_string(&path) {
+            Ok(contents) => Ok(Some(contents)),
+            Err(err) if err.kind() == io::ErrorKind::NotFound => Ok(None),
+            Err(source) => Err(Error::Io { path, source }),
+        }
+    }
+
+    pub fn store(&self, year: u16, day: u8, contents: &str) -> Result<PathBuf, Error> {
+        let path = self.path(year, day);
+        let io_error = |source| Error::Io {
+            path: path.clone(),
+            source,
+        };
+
+        if let Some(parent) = path.parent() {
+            fs::create_dir_all(parent).map_err(io_error)?;
+        }
+
+        // Write to a temporary file first so an interrupted download never leaves a
+        // truncated input behind
+        let partial = path.with_extension("txt.partial");
+        fs::write(&partial, contents).map_err(io_error)?;
+        fs::rename(&partial, &path).map_err(io_error)?;
+
+        Ok(path)
+    }
+}
+
+/// `$AOC_CACHE_DIR` is handled by clap; this is the platform default after that.
+fn default_cache_dir() -> Option<PathBuf> {
+    let base = std::env::var_os("XDG_CACHE_HOME")
+        .map(PathBuf::from)
+        .or_else(|| std::env::var_os("LOCALAPPDATA").map(PathBuf::from))
+        .or_else(|| std::env::var_os("HOME").map(|home| Path::new(&home).join(".cache")))?;
+
+    Some(base.join("aoc-24"))
+}
+
+/// Returns a day's input, from the cache if possible and from the server otherwise. When
+/// `refresh` is set the server is always asked first, still falling back to the cache if it
+/// cannot be reached.
+pub fn fetch_input(
+    client: &Client,
+    cache: &InputCache,
+    year: u16,
+    day: u8,
+    refresh: bool,
+) -> Result<String, Error> {
+    if !refresh && let Some(cached) = cache.get(year, day)? {
+        return Ok(cached);
+    }
+
+    match client.input(year, day) {
+        Ok(input) => {
+            cache.store(year, day, &input)?;
+            Ok(input)
+        }
+        Err(err) => match cache.get(year, day)? {
+            Some(cached) => {
+                eprintln!("warning: {err}; using the cached input");
+                Ok(cached)
+            }
+            None => Err(err),
+        },
+    }
+}
+
+pub fn fetch(args: FetchArgs) -> Result<(), Error> {
+    let cache = InputCache::from_args(&args.cache)?;
+    let client = Client::new(&args.server);
+
+    fetch_input(&client, &cache, args.server.year, args.day, args.refresh)?;
+
+    println!("{}", cache.path(args.server.year, args.day).display());
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::testing::{MockServer, unreachable_url};
+
+    fn client(base_url: &str) -> Client {
+        Client::new(&ServerArgs {
+            base_url: base_url.to_string(),
+            session: Some("secret".to_string()),
+            year: 2024,
+        })
+    }
+
+    #[test]
+    fn downloads_and_caches_the_input() {
+        let dir = tempfile::tempdir().unwrap();
+        let cache = InputCache::new(dir.path());
+        let server = MockServer::start(vec![(200, "1 2\n3 4\n")]);
+
+        let input = fetch_input(&client(&server.url), &cache, 2024, 1, false).unwrap();
+        assert_eq!(input, "1 2\n3 4\n");
+
+        let requests = server.requests();
+        assert_eq!(requests.len(), 1);
+        assert_eq!(requests[0].method, "GET");
+        assert_eq!(requests[0].path, "/2024/day/1/input");
+        assert_eq!(requests[0].header("cookie"), Some("session=secret"));
+
+        let cached = fs::read_to_string(dir.path().join("2024/01.txt")).unwrap();
+        assert_eq!(cached, input);
+    }
+
+    #[test]
+    fn uses_the_cache_without_asking_the_server() {
+        let dir = tempfile::tempdir().unwrap();
+        let cache = InputCache::new(dir.path());
+        cache.store(2024, 6, "cached").unwrap();
+
+        let input = fetch_input(&client(&unreachable_url()), &cache, 2024, 6, false).unwrap();
+        assert_eq!(input, "cached");
+    }
+
+    #[test]
+    fn refresh_falls_back_to_the_cache_when_offline() {
+        let dir = tempfile::tempdir().unwrap();
+        let cache = InputCache::new(dir.path());
+        cache.store(2024, 6, "cached").unwrap();
+
+        let input = fetch_input(&client(&unreachable_url()), &cache, 2024, 6, true).unwrap();
+        assert_eq!(input, "cached");
+    }
+
+    #[test]
+    fn refresh_replaces_the_cached_input() {
+        let dir = tempfile::tempdir().unwrap();
+        let cache = InputCache::new(dir.path());
+        cache.store(2024, 6, "stale").unwrap();
+        let server = MockServer::start(vec![(200, "fresh")]);
+
+        let input = fetch_input(&client(&server.url), &cache, 2024, 6, true).unwrap();
+        assert_eq!(input, "fresh");
+        assert_eq!(cache.get(2024, 6).unwrap().as_deref(), Some("fresh"));
+    }
+
+    #[test]
+    fn error_statuses_are_not_cached() {
+        let dir = tempfile::tempdir().unwrap();
+        let cache = InputCache::new(dir.path());
+        let server = MockServer::start(vec![(400, "Please log in")]);
+
+        let err = fetch_input(&client(&server.url), &cache, 2024, 3, false).unwrap_err();
+        assert!(err.to_string().contains("400: Please log in"), "{err}");
+        assert_eq!(cache.get(2024, 3).unwrap(), None);
+    }
+
+    #[test]
+    fn requires_a_session_token() {
+        let dir = tempfile::tempdir().unwrap();
+        let cache = InputCache::new(dir.path());
+        let client = Client::new(&ServerArgs {
+            base_url: unreachable_url(),
+            session: None,
+            year: 2024,
+        });
+
+        let err = fetch_input(&client, &cache, 2024, 3, false).unwrap_err();
+        assert!(matches!(err, Error::MissingSession));
+    }
+}
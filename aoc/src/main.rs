@@ -0,0 +1,75 @@
+mod bench;
+mod client;
+mod d1;
+mod d2;
+mod days;
+mod diff;
+mod error;
+mod generate;
+mod inputs;
+mod output;
+mod run;
+mod scaffold;
+mod submit;
+#[cfg(test)]
+mod testing;
+mod visualize;
+mod watch;
+
+use clap::{Parser, Subcommand};
+use std::process::ExitCode;
+
+#[derive(Parser)]
+#[command(name = "aoc", about = "Advent of Code 2024 solutions")]
+struct Cli {
+    #[command(subcommand)]
+    command: Command,
+}
+
+#[derive(Subcommand)]
+enum Command {
+    /// Solve one or all days
+    Run(run::RunArgs),
+    /// Time every implementation of one or all days
+    Bench(bench::BenchArgs),
+    /// Download a day's input into the local cache
+    Fetch(inputs::FetchArgs),
+    /// Submit an answer, refusing ones already known to be wrong
+    Submit(submit::SubmitArgs),
+    /// Create the crate for a new day and register it with the runner
+    NewDay(scaffold::NewDayArgs),
+    /// Re-run a day whenever its code, input or fixtures change
+    Watch(watch::WatchArgs),
+    /// Generate a random input for a day, with its answers where they are known
+    Generate(generate::GenerateArgs),
+    /// Compare a day's implementations on generated inputs, shrinking any disagreement
+    Diff(diff::DiffArgs),
+    /// Day 1 tools beyond solving the puzzle
+    D1(d1::D1Args),
+    /// Day 2 tools beyond solving the puzzle
+    D2(d2::D2Args),
+}
+
+fn main() -> ExitCode {
+    let cli = Cli::parse();
+
+    let result = match cli.command {
+        Command::Run(args) => run::run(args),
+        Command::Bench(args) => bench::bench(args),
+        Command::Fetch(args) => inputs::fetch(args),
+        Command::Submit(args) => submit::submit(args),
+        Command::NewDay(args) => scaffold::new_day(args),
+        Command::Watch(args) => watch::watch(args),
+        Command::Generate(args) => generate::generate(args),
+        Command::Diff(args) => diff::diff(args),
+        Command::D1(args) => d1::d1(args),
+        Command::D2(args) => d2::d2(args),
+    };
+
+    if let Err(err) = result {
+        eprintln!("error: {err}");
+        return ExitCode::FAILURE;
+    }
+
+    ExitCode::SUCCESS
+}
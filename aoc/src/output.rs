@@ -0,0 +1,40 @@
+use clap::ValueEnum;
+use serde::{Deserialize, Serialize};
+use sha2::{Digest, Sha256};
+
+/// Version of the JSON documents printed with `--format json`. Bumped whenever a field is
+/// renamed, removed or changes meaning; adding fields does not bump it.
+pub const SCHEMA_VERSION: u32 = 1;
+
+#[derive(Clone, Copy, Default, ValueEnum)]
+pub enum Format {
+    #[default]
+    Text,
+    Json,
+}
+
+/// The JSON document wrapping a command's results.
+#[derive(Serialize, Deserialize)]
+pub struct Document<T> {
+    pub schema_version: u32,
+    pub results: T,
+}
+
+pub fn print_json<T: Serialize>(results: T) {
+    let document = Document {
+        schema_version: SCHEMA_VERSION,
+        results,
+    };
+    println!(
+        "{}",
+        serde_json::to_string_pretty(&document).expect("results serialize to JSON")
+    );
+}
+
+/// Hex-encoded SHA-256 of an input, so results can be matched to the input they came from.
+pub fn input_hash(input: &str) -> String {
+    Sha256::digest(input.as_bytes())
+        .iter()
+        .map(|b| format!("{b:02x}"))
+        .collect()
+}
@@ -0,0 +1,167 @@
+use crate::days::{self, DAYS, Day};
+use crate::error::Error;
+use crate::inputs::InputArgs;
+use crate::output::{self, Format, input_hash};
+use crate::visualize::{self, VisualizeArgs};
+use clap::Args;
+use serde::Serialize;
+use std::time::{Duration, Instant};
+
+#[derive(Args)]
+pub struct RunArgs {
+    /// Day to run. Runs every day in sequence if omitted.
+    #[arg(long)]
+    day: Option<u8>,
+
+    /// Part to run. Runs both parts if omitted.
+    #[arg(long, value_parser = clap::value_parser!(u8).range(1..=2))]
+    part: Option<u8>,
+
+    #[command(flatten)]
+    input: InputArgs,
+
+    #[arg(long, value_enum, default_value_t)]
+    format: Format,
+
+    #[command(flatten)]
+    visualize: VisualizeArgs,
+}
+
+/// One solved part, as reported in JSON output.
+#[derive(Serialize)]
+struct Answer {
+    day: u8,
+    part: u8,
+    answer: String,
+    implementation: &'static str,
+    #[serde(rename = "duration_ns", serialize_with = "serialize_nanos")]
+    duration: Duration,
+    input_sha256: String,
+}
+
+fn serialize_nanos<S: serde::Serializer>(duration: &Duration, s: S) -> Result<S::Ok, S::Error> {
+    s.serialize_u64(duration.as_nanos() as u64)
+}
+
+pub fn run(args: RunArgs) -> Result<(), Error> {
+    let selected: Vec<&Day> = match args.day {
+        Some(day) => vec![days::find(day).ok_or(Error::UnknownDay(day))?],
+        None => DAYS.iter().collect(),
+    };
+    let parts = match args.part {
+        Some(part) => vec![part],
+        None => vec![1, 2],
+    };
+
+    let mut answers = Vec::new();
+
+    for day in selected {
+        let (path, input) = args.input.load(day)?;
+        let hash = input_hash(&input);
+
+        for part in &parts {
+            let start = Instant::now();
+            let answer = day.part(*part)(&input)
+                .map_err(|err| err.with_file(&path))?
+                .ok_or(Error::Overflow(day.day))?;
+            let duration = start.elapsed();
+
+            if args.visualize.visualize {
+                visualize::draw(
+                    &args.visualize,
+                    day,
+                    *part,
+                    (&path, &input),
+                    parts.len() > 1,
+                )?;
+            }
+
+            answers.push(Answer {
+                day: day.day,
+                part: *part,
+                answer,
+                implementation: "default",
+                duration,
+                input_sha256: hash.clone(),
+            });
+        }
+    }
+
+    match args.format {
+        Format::Text => print_summary(&answers),
+        Format::Json => output::print_json(&answers),
+    }
+    Ok(())
+}
+
+fn print_summary(answers: &[Answer]) {
+    let width = answers
+        .iter()
+        .map(|a| a.answer.len())
+        .max()
+        .unwrap_or(0)
+        .max("Answer".len());
+
+    println!(
+        "{:>3}  {:>4}  {:<width$}  {:>12}",
+        "Day", "Part", "Answer", "Time"
+    );
+    for a in answers {
+        println!(
+            "{:>3}  {:>4}  {:<width$}  {:>12}",
+            a.day,
+            a.part,
+            a.answer,
+            format!("{:.2?}", a.duration)
+        );
+    }
+
+    if answers.len() > 1 {
+        let total: Duration = answers.iter().map(|a| a.duration).sum();
+        println!(
+            "{:>3}  {:>4}  {:<width$}  {:>12}",
+            "",
+            "",
+            "Total",
+            format!("{:.2?}", total)
+        );
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn json_schema_is_stable() {
+        let answer = Answer {
+            day: 6,
+            part: 2,
+            answer: "1770".to_string(),
+            implementation: "default",
+            duration: Duration::from_micros(1500),
+            input_sha256: input_hash(""),
+        };
+
+        let json = serde_json::to_value(output::Document {
+            schema_version: output::SCHEMA_VERSION,
+            results: [answer],
+        })
+        .unwrap();
+
+        assert_eq!(
+            json,
+            serde_json::json!({
+                "schema_version": 1,
+                "results": [{
+                    "day": 6,
+                    "part": 2,
+                    "answer": "1770",
+                    "implementation": "default",
+                    "duration_ns": 1_500_000,
+                    "input_sha256": "e3b0c44298fc1c149afbf4c8996fb92427ae41e4649b934ca495991b7852b855",
+                }],
+            })
+        );
+    }
+}
@@ -0,0 +1,275 @@
+use crate::error::Error;
+use crate::inputs::workspace_root;
+use clap::Args;
+use std::fs;
+use std::path::{Path, PathBuf};
+
+#[derive(Args)]
+pub struct NewDayArgs {
+    /// Day to create a crate for.
+    #[arg(long, value_parser = clap::value_parser!(u8).range(1..=25))]
+    day: u8,
+}
+
+/// Files of a new day's crate, relative to the crate, with `{{day}}` standing for the day.
+const TEMPLATES: &[(&str, &str)] = &[
+    ("Cargo.toml", include_str!("../templates/Cargo.toml.tmpl")),
+    ("src/lib.rs", include_str!("../templates/lib.rs.tmpl")),
+    (
+        "tests/fixtures.rs",
+        include_str!("../templates/fixtures.rs.tmpl"),
+    ),
+    (
+        "fixtures/answers.txt",
+        include_str!("../templates/answers.txt.tmpl"),
+    ),
+    ("fixtures/example.txt", ""),
+];
+
+/// Creates the crate for `day` under `root` and registers it with the workspace and the
+/// runner. Returns the files created.
+pub fn scaffold(root: &Path, day: u8) -> Result<Vec<PathBuf>, Error> {
+    let name = format!("d{day}");
+    let dir = root.join(&name);
+    if dir.exists() {
+        return Err(Error::Scaffold(format!("{} already exists", dir.display())));
+    }
+
+    // Work out every edit before touching the disk, so a workspace we don't understand is
+    // left as it was
+    let edits = [
+        edit(root, "Cargo.toml", |manifest| {
+            let manifest = add_member(manifest, &name)?;
+            insert_after_last(
+                &manifest,
+                |line| is_day_line(line, " = { path = "),
+                &format!("{name} = {{ path = \"{name}\" }}"),
+            )
+        })?,
+        edit(root, "aoc/Cargo.toml", |manifest| {
+            insert_after_last(
+                manifest,
+                |line| is_day_line(line, ".workspace = true"),
+                &format!("{name}.workspace = true"),
+            )
+        })?,
+        edit(root, "aoc/src/days.rs", |days| {
+            insert_after_last(
+                days,
+                |line| line.trim_start().starts_with("Day::new::<"),
+                &format!("    Day::new::<{name}::Day{day}>(\"{name}/data.txt\"),"),
+            )
+        })?,
+    ];
+
+    // Anything half done is undone, so a failure never leaves a crate the workspace doesn't
+    // know about, or registers one that isn't there
+    let created = match write_templates(&dir, day) {
+        Ok(created) => created,
+        Err(err) => {
+            let _ = fs::remove_dir_all(&dir);
+            return Err(err);
+        }
+    };
+    for (i, (path, _, contents)) in edits.iter().enumerate() {
+        if let Err(source) = fs::write(path, contents) {
+            for (path, original, _) in &edits[..=i] {
+                let _ = fs::write(path, original);
+            }
+            let _ = fs::remove_dir_all(&dir);
+            return Err(Error::Io {
+                path: path.clone(),
+                source,
+            });
+        }
+    }
+
+    Ok(created)
+}
+
+fn write_templates(dir: &Path, day: u8) -> Result<Vec<PathBuf>, Error> {
+    let mut created = Vec::new();
+    for (file, template) in TEMPLATES {
+        let path = dir.join(file);
+        let io_error = |source| Error::Io {
+            path: path.clone(),
+            source,
+        };
+
+        if let Some(parent) = path.parent() {
+            fs::create_dir_all(parent).map_err(io_error)?;
+        }
+        fs::write(&path, template.replace("{{day}}", &day.to_string())).map_err(io_error)?;
+        created.push(path);
+    }
+
+    Ok(created)
+}
+
+/// Reads `file` and works out its edited contents, giving its path, its original contents
+/// and the edited ones.
+fn edit(
+    root: &Path,
+    file: &str,
+    f: impl FnOnce(&str) -> Option<String>,
+) -> Result<(PathBuf, String, String), Error> {
+    let path = root.join(file);
+    let contents = fs::read_to_string(&path).map_err(|source| Error::Io {
+        path: path.clone(),
+        source,
+    })?;
+
+    match f(&contents) {
+        Some(edited) => Ok((path, contents, edited)),
+        None => Err(Error::Scaffold(format!(
+            "{}: could not find where to register the new day",
+            path.display()
+        ))),
+    }
+}
+
+/// Whether `line` starts with a day crate's name followed by `rest`, like `d7 = ...`.
+fn is_day_line(line: &str, rest: &str) -> bool {
+    let Some(number) = line.strip_prefix('d') else {
+        return false;
+    };
+    let after = number.trim_start_matches(|c: char| c.is_ascii_digit());
+
+    after.len() < number.len() && after.starts_with(rest)
+}
+
+fn add_member(manifest: &str, name: &str) -> Option<String> {
+    let start = manifest.find("members = [")?;
+    let end = start + manifest[start..].find(']')?;
+
+    Some(format!(
+        "{}, \"{name}\"{}",
+        &manifest[..end],
+        &manifest[end..]
+    ))
+}
+
+fn insert_after_last(contents: &str, matches: impl Fn(&str) -> bool, line: &str) -> Option<String> {
+    let mut lines: Vec<&str> = contents.lines().collect();
+    let last = lines.iter().rposition(|l| matches(l))?;
+    lines.insert(last + 1, line);
+
+    Some(lines.join("\n") + "\n")
+}
+
+pub fn new_day(args: NewDayArgs) -> Result<(), Error> {
+    let root = workspace_root();
+
+    for path in scaffold(root, args.day)? {
+        println!(
+            "created {}",
+            path.strip_prefix(root).unwrap_or(&path).display()
+        );
+    }
+    println!("registered d{} in the workspace and the runner", args.day);
+
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    /// A copy of the files `scaffold` edits, in a temporary workspace.
+    fn workspace() -> tempfile::TempDir {
+        let dir = tempfile::tempdir().unwrap();
+        for file in ["Cargo.toml", "aoc/Cargo.toml", "aoc/src/days.rs"] {
+            let path = dir.path().join(file);
+            fs::create_dir_all(path.parent().unwrap()).unwrap();
+            fs::copy(workspace_root().join(file), path).unwrap();
+        }
+        dir
+    }
+
+    fn read(dir: &Path, file: &str) -> String {
+        fs::read_to_string(dir.join(file)).unwrap()
+    }
+
+    #[test]
+    fn creates_and_registers_the_crate() {
+        let dir = workspace();
+        let created = scaffold(dir.path(), 11).unwrap();
+        assert_eq!(created.len(), TEMPLATES.len());
+
+        let lib = read(dir.path(), "d11/src/lib.rs");
+        assert!(lib.contains("pub struct Day11;"));
+        assert!(lib.contains("const DAY: u8 = 11;"));
+        assert!(!lib.contains("{{day}}"));
+
+        let manifest = read(dir.path(), "Cargo.toml");
+        assert!(manifest.contains("\"d10\", \"d11\"]"));
+        assert!(manifest.contains("d10 = { path = \"d10\" }\nd11 = { path = \"d11\" }\n"));
+
+        let runner = read(dir.path(), "aoc/Cargo.toml");
+        assert!(runner.contains("d10.workspace = true\nd11.workspace = true\n"));
+
+        let days = read(dir.path(), "aoc/src/days.rs");
+        assert!(days.contains("    Day::new::<d11::Day11>(\"d11/data.txt\"),\n];"));
+    }
+
+    #[test]
+    fn refuses_to_overwrite_a_day() {
+        let dir = workspace();
+        fs::create_dir(dir.path().join("d3")).unwrap();
+        let before = read(dir.path(), "Cargo.toml");
+
+        let err = scaffold(dir.path(), 3).unwrap_err();
+        assert!(matches!(err, Error::Scaffold(_)), "{err}");
+        assert_eq!(read(dir.path(), "Cargo.toml"), before);
+    }
+
+    #[test]
+    fn leaves_an_unfamiliar_workspace_alone() {
+        let dir = workspace();
+        fs::write(dir.path().join("aoc/src/days.rs"), "").unwrap();
+
+        let err = scaffold(dir.path(), 11).unwrap_err();
+        assert!(matches!(err, Error::Scaffold(_)), "{err}");
+        assert!(!dir.path().join("d11").exists());
+    }
+
+    fn copy_dir(from: &Path, to: &Path) {
+        fs::create_dir_all(to).unwrap();
+        for entry in fs::read_dir(from).unwrap() {
+            let entry = entry.unwrap();
+            let name = entry.file_name();
+            if name == "target" || name == ".git" {
+                continue;
+            }
+
+            match entry.file_type().unwrap().is_dir() {
+                true => copy_dir(&entry.path(), &to.join(&name)),
+                false => {
+                    fs::copy(entry.path(), to.join(&name)).unwrap();
+                }
+            }
+        }
+    }
+
+    #[test]
+    fn scaffolded_crate_compiles() {
+        let dir = tempfile::tempdir().unwrap();
+        copy_dir(workspace_root(), dir.path());
+        scaffold(dir.path(), 11).unwrap();
+
+        // Shares a target directory with other runs so only the new day is built from scratch
+        let cargo = std::env::var_os("CARGO").unwrap_or_else(|| "cargo".into());
+        let output = std::process::Command::new(cargo)
+            .args(["check", "--offline", "--quiet", "-p", "d11", "-p", "aoc"])
+            .current_dir(dir.path())
+            .env("CARGO_TARGET_DIR", workspace_root().join("target/scaffold"))
+            .output()
+            .unwrap();
+
+        assert!(
+            output.status.success(),
+            "{}",
+            String::from_utf8_lossy(&output.stderr)
+        );
+    }
+}
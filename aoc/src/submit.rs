@@ -0,0 +1,403 @@
+use crate::client::Client;
+use crate::days;
+use crate::error::Error;
+use crate::inputs::InputArgs;
+use clap::Args;
+use serde::{Deserialize, Serialize};
+use std::fs::{self, OpenOptions};
+use std::io::{self, Write};
+use std::path::PathBuf;
+use std::time::{SystemTime, UNIX_EPOCH};
+
+#[derive(Args)]
+pub struct SubmitArgs {
+    /// Day to submit.
+    #[arg(long)]
+    day: u8,
+
+    /// Part to submit.
+    #[arg(long, value_parser = clap::value_parser!(u8).range(1..=2))]
+    part: u8,
+
+    /// Answer to submit. Solved from the day's input if omitted.
+    #[arg(long)]
+    answer: Option<String>,
+
+    /// File every submission is recorded in. Defaults to `history.jsonl` in the cache
+    /// directory.
+    #[arg(long, env = "AOC_HISTORY")]
+    history: Option<PathBuf>,
+
+    #[command(flatten)]
+    input: InputArgs,
+}
+
+/// How the puzzle server judged a submitted answer.
+#[derive(Clone, Debug, Eq, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case", tag = "outcome")]
+pub enum Outcome {
+    Correct,
+    TooHigh,
+    TooLow,
+    /// Wrong, without a hint in which direction.
+    Incorrect,
+    /// Submitted too soon after a previous attempt, with the wait if the server said.
+    RateLimited {
+        wait: Option<String>,
+    },
+    /// The part was already solved, or is not unlocked yet.
+    WrongLevel,
+    Unknown {
+        message: String,
+    },
+}
+
+impl Outcome {
+    pub fn is_wrong(&self) -> bool {
+        matches!(
+            self,
+            Outcome::TooHigh | Outcome::TooLow | Outcome::Incorrect
+        )
+    }
+
+    /// Classifies the server's HTML reply to a submission.
+    pub fn parse(html: &str) -> Outcome {
+        let text = article_text(html);
+
+        if text.contains("That's the right answer") {
+            Outcome::Correct
+        } else if text.contains("your answer is too high") {
+            Outcome::TooHigh
+        } else if text.contains("your answer is too low") {
+            Outcome::TooLow
+        } else if text.contains("That's not the right answer") {
+            Outcome::Incorrect
+        } else if text.contains("You gave an answer too recently") {
+            let wait = text
+                .split_once("You have ")
+                .and_then(|(_, rest)| rest.split_once(" left to wait"))
+                .map(|(wait, _)| wait.to_string());
+            Outcome::RateLimited { wait }
+        } else if text.contains("You don't seem to be solving the right level") {
+            Outcome::WrongLevel
+        } else {
+            Outcome::Unknown { message: text }
+        }
+    }
+}
+
+/// The text of the reply's `<article>`, or of the whole reply if there is none, with tags
+/// stripped and whitespace collapsed.
+fn article_text(html: &str) -> String {
+    let article = html
+        .split_once("<article")
+        .and_then(|(_, rest)| rest.split_once('>'))
+        .and_then(|(_, rest)| rest.split_once("</article>"))
+        .map_or(html, |(article, _)| article);
+
+    let mut text = String::new();
+    let mut in_tag = false;
+    for c in article.chars() {
+        match c {
+            '<' => in_tag = true,
+            '>' => in_tag = false,
+            c if !in_tag => text.push(c),
+            _ => {}
+        }
+    }
+
+    text.split_whitespace().collect::<Vec<_>>().join(" ")
+}
+
+/// A submission, as recorded in the history file.
+#[derive(Clone, Debug, Eq, PartialEq, Serialize, Deserialize)]
+pub struct Attempt {
+    pub year: u16,
+    pub day: u8,
+    pub part: u8,
+    pub answer: String,
+    #[serde(flatten)]
+    pub outcome: Outcome,
+    /// Seconds since the Unix epoch.
+    pub timestamp: u64,
+}
+
+/// Every answer submitted so far, stored one JSON object per line.
+pub struct History {
+    path: PathBuf,
+    attempts: Vec<Attempt>,
+}
+
+impl History {
+    pub fn load(path: impl Into<PathBuf>) -> Result<Self, Error> {
+        let path = path.into();
+
+        let contents = match fs::read_to_string(&path) {
+            Ok(contents) => contents,
+            Err(err) if err.kind() == io::ErrorKind::NotFound => String::new(),
+            Err(source) => return Err(Error::Io { path, source }),
+        };
+
+        let attempts = contents
+            .lines()
+            .filter(|line| !line.trim().is_empty())
+            .map(serde_json::from_str)
+            .collect::<Result<_, _>>()
+            .map_err(|err| Error::Io {
+                path: path.clone(),
+                source: io::Error::new(io::ErrorKind::InvalidData, err),
+            })?;
+
+        Ok(Self { path, attempts })
+    }
+
+    pub fn record(&mut self, attempt: Attempt) -> Result<(), Error> {
+        let io_error = |source| Error::Io {
+            path: self.path.clone(),
+            source,
+        };
+
+        if let Some(parent) = self.path.parent() {
+            fs::create_dir_all(parent).map_err(io_error)?;
+        }
+
+        let mut file = OpenOptions::new()
+            .create(true)
+            .append(true)
+            .open(&self.path)
+            .map_err(io_error)?;
+        let line = serde_json::to_string(&attempt).expect("attempts serialize to JSON");
+        writeln!(file, "{line}").map_err(io_error)?;
+
+        self.attempts.push(attempt);
+        Ok(())
+    }
+
+    /// Explains why `answer` should not be submitted, if the history already rules it out.
+    pub fn check(&self, year: u16, day: u8, part: u8, answer: &str) -> Result<(), String> {
+        let attempts = || {
+            self.attempts
+                .iter()
+                .filter(move |a| a.year == year && a.day == day && a.part == part)
+        };
+
+        if let Some(correct) = attempts().find(|a| a.outcome == Outcome::Correct) {
+            return Err(format!("part already solved with {}", correct.answer));
+        }
+
+        if let Some(wrong) = attempts().find(|a| a.answer == answer && a.outcome.is_wrong()) {
+            return Err(format!(
+                "{answer} was already rejected ({:?})",
+                wrong.outcome
+            ));
+        }
+
+        let Ok(value) = answer.parse::<i128>() else {
+            return Ok(());
+        };
+        let bound = |outcome: Outcome| {
+            attempts()
+                .filter(move |a| a.outcome == outcome)
+                .filter_map(|a| a.answer.parse::<i128>().ok())
+        };
+
+        if let Some(high) = bound(Outcome::TooHigh).min()
+            && value >= high
+        {
+            return Err(format!("{answer} is not below {high}, which was too high"));
+        }
+        if let Some(low) = bound(Outcome::TooLow).max()
+            && value <= low
+        {
+            return Err(format!("{answer} is not above {low}, which was too low"));
+        }
+
+        Ok(())
+    }
+}
+
+/// Submits an answer unless the history rules it out, recording the outcome.
+pub fn submit_answer(
+    client: &Client,
+    history: &mut History,
+    year: u16,
+    day: u8,
+    part: u8,
+    answer: &str,
+) -> Result<Outcome, Error> {
+    history
+        .check(year, day, part, answer)
+        .map_err(Error::Refused)?;
+
+    let outcome = Outcome::parse(&client.submit(year, day, part, answer)?);
+
+    history.record(Attempt {
+        year,
+        day,
+        part,
+        answer: answer.to_string(),
+        outcome: outcome.clone(),
+        timestamp: SystemTime::now()
+            .duration_since(UNIX_EPOCH)
+            .map_or(0, |d| d.as_secs()),
+    })?;
+
+    Ok(outcome)
+}
+
+pub fn submit(args: SubmitArgs) -> Result<(), Error> {
+    let answer = match args.answer {
+        Some(answer) => answer,
+        None => {
+            let day = days::find(args.day).ok_or(Error::UnknownDay(args.day))?;
+            let (path, input) = args.input.load(day)?;
+            day.part(args.part)(&input)
+                .map_err(|err| err.with_file(&path))?
+                .ok_or(Error::Overflow(day.day))?
+        }
+    };
+
+    let history_path = match args.history {
+        Some(path) => path,
+        None => args.input.cache.dir()?.join("history.jsonl"),
+    };
+    let mut history = History::load(history_path)?;
+    let client = Client::new(&args.input.server);
+
+    let outcome = submit_answer(
+        &client,
+        &mut history,
+        args.input.server.year,
+        args.day,
+        args.part,
+        &answer,
+    )?;
+
+    match outcome {
+        Outcome::Correct => println!("{answer} is correct"),
+        Outcome::TooHigh => println!("{answer} is too high"),
+        Outcome::TooLow => println!("{answer} is too low"),
+        Outcome::Incorrect => println!("{answer} is not the right answer"),
+        Outcome::RateLimited { wait: Some(wait) } => println!("rate limited, wait {wait}"),
+        Outcome::RateLimited { wait: None } => println!("rate limited"),
+        Outcome::WrongLevel => println!("part {} is already solved or locked", args.part),
+        Outcome::Unknown { message } => println!("unrecognised reply: {message}"),
+    }
+
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::client::ServerArgs;
+    use crate::testing::{MockServer, unreachable_url};
+
+    const TOO_HIGH: &str = "<html><main><article><p>That's not the right answer; your answer is \
+        too high. If you're stuck, make sure you're using the full input data.</p></article>\
+        </main></html>";
+
+    fn client(base_url: &str) -> Client {
+        Client::new(&ServerArgs {
+            base_url: base_url.to_string(),
+            session: Some("secret".to_string()),
+            year: 2024,
+        })
+    }
+
+    fn attempt(part: u8, answer: &str, outcome: Outcome) -> Attempt {
+        Attempt {
+            year: 2024,
+            day: 1,
+            part,
+            answer: answer.to_string(),
+            outcome,
+            timestamp: 0,
+        }
+    }
+
+    #[test]
+    fn parses_server_replies() {
+        assert_eq!(Outcome::parse(TOO_HIGH), Outcome::TooHigh);
+        assert_eq!(
+            Outcome::parse("<article><p>That's the right answer!</p></article>"),
+            Outcome::Correct
+        );
+        assert_eq!(
+            Outcome::parse("<article><p>That's not the right answer.</p></article>"),
+            Outcome::Incorrect
+        );
+        assert_eq!(
+            Outcome::parse(
+                "<article><p>You gave an answer too recently; you have to wait after \
+                 submitting an answer before trying again. You have 38s left to wait.</p></article>"
+            ),
+            Outcome::RateLimited {
+                wait: Some("38s".to_string())
+            }
+        );
+        assert_eq!(
+            Outcome::parse(
+                "<article><p>You don't seem to be solving the right level.</p></article>"
+            ),
+            Outcome::WrongLevel
+        );
+    }
+
+    #[test]
+    fn refuses_answers_the_history_rules_out() {
+        let dir = tempfile::tempdir().unwrap();
+        let mut history = History::load(dir.path().join("history.jsonl")).unwrap();
+        history.record(attempt(1, "100", Outcome::TooHigh)).unwrap();
+        history.record(attempt(1, "10", Outcome::TooLow)).unwrap();
+        history
+            .record(attempt(1, "42", Outcome::Incorrect))
+            .unwrap();
+        history.record(attempt(2, "7", Outcome::Correct)).unwrap();
+
+        assert!(history.check(2024, 1, 1, "100").is_err());
+        assert!(history.check(2024, 1, 1, "150").is_err());
+        assert!(history.check(2024, 1, 1, "5").is_err());
+        assert!(history.check(2024, 1, 1, "42").is_err());
+        assert!(history.check(2024, 1, 2, "8").is_err());
+        assert!(history.check(2024, 1, 1, "50").is_ok());
+        assert!(history.check(2024, 2, 1, "100").is_ok());
+
+        // The history survives being reloaded
+        let reloaded = History::load(dir.path().join("history.jsonl")).unwrap();
+        assert_eq!(reloaded.attempts, history.attempts);
+    }
+
+    #[test]
+    fn posts_the_answer_and_records_the_outcome() {
+        let dir = tempfile::tempdir().unwrap();
+        let mut history = History::load(dir.path().join("history.jsonl")).unwrap();
+        let server = MockServer::start(vec![(200, TOO_HIGH)]);
+
+        let outcome =
+            submit_answer(&client(&server.url), &mut history, 2024, 1, 2, "1234").unwrap();
+        assert_eq!(outcome, Outcome::TooHigh);
+
+        let requests = server.requests();
+        assert_eq!(requests.len(), 1);
+        assert_eq!(requests[0].method, "POST");
+        assert_eq!(requests[0].path, "/2024/day/1/answer");
+        assert_eq!(requests[0].header("cookie"), Some("session=secret"));
+        assert_eq!(requests[0].body, "level=2&answer=1234");
+
+        assert_eq!(history.attempts.len(), 1);
+        assert_eq!(history.attempts[0].outcome, Outcome::TooHigh);
+    }
+
+    #[test]
+    fn refused_answers_never_reach_the_server() {
+        let dir = tempfile::tempdir().unwrap();
+        let mut history = History::load(dir.path().join("history.jsonl")).unwrap();
+        history.record(attempt(1, "10", Outcome::TooLow)).unwrap();
+
+        let err =
+            submit_answer(&client(&unreachable_url()), &mut history, 2024, 1, 1, "9").unwrap_err();
+        assert!(matches!(err, Error::Refused(_)), "{err}");
+        assert_eq!(history.attempts.len(), 1);
+    }
+}
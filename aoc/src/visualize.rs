@@ -0,0 +1,129 @@
+use crate::days::Day;
+use crate::error::Error;
+use aoc_common::render::{self, Frame};
+use clap::{Args, ValueEnum};
+use std::fs;
+use std::io::{self, IsTerminal, Write};
+use std::path::{Path, PathBuf};
+
+#[derive(Args)]
+pub struct VisualizeArgs {
+    /// Draw how each part is solved, for the days that support it.
+    #[arg(long, requires = "day")]
+    pub visualize: bool,
+
+    /// How to draw. Defaults to ANSI colours on a terminal and plain text otherwise.
+    #[arg(long, value_enum, requires = "visualize")]
+    render: Option<RenderFormat>,
+
+    /// Write the drawing to this file instead of stdout. When running both parts, the part
+    /// is added to the file name.
+    #[arg(
+        long,
+        requires = "visualize",
+        required_if_eq_all([("format", "json"), ("visualize", "true")])
+    )]
+    render_to: Option<PathBuf>,
+}
+
+#[derive(Clone, Copy, ValueEnum)]
+enum RenderFormat {
+    Ansi,
+    Plain,
+    Ppm,
+    Svg,
+}
+
+impl From<RenderFormat> for render::Format {
+    fn from(format: RenderFormat) -> Self {
+        match format {
+            RenderFormat::Ansi => render::Format::Ansi,
+            RenderFormat::Plain => render::Format::Plain,
+            RenderFormat::Ppm => render::Format::Ppm,
+            RenderFormat::Svg => render::Format::Svg,
+        }
+    }
+}
+
+/// Draws one part of a day, warning instead if the day has no visualization.
+pub fn draw(
+    args: &VisualizeArgs,
+    day: &Day,
+    part: u8,
+    (path, input): (&Path, &str),
+    both_parts: bool,
+) -> Result<(), Error> {
+    let Some(mut frame) = (day.visualize)(input, part).map_err(|err| err.with_file(path))? else {
+        eprintln!("warning: day {} part {part} has no visualization", day.day);
+        return Ok(());
+    };
+    frame.set_title(format!("Day {} part {part}", day.day));
+
+    match &args.render_to {
+        Some(file) => {
+            let file = if both_parts {
+                part_file(file, part)
+            } else {
+                file.clone()
+            };
+            let format = args.render.map_or_else(|| format_for(&file), Into::into);
+            write_frame(&frame, format, &file)
+        }
+        None => {
+            let format = args.render.map_or_else(
+                || match io::stdout().is_terminal() {
+                    true => render::Format::Ansi,
+                    false => render::Format::Plain,
+                },
+                Into::into,
+            );
+            io::stdout()
+                .write_all(&frame.render(format))
+                .map_err(|source| Error::Io {
+                    path: PathBuf::from("<stdout>"),
+                    source,
+                })
+        }
+    }
+}
+
+fn write_frame(frame: &Frame, format: render::Format, file: &Path) -> Result<(), Error> {
+    fs::write(file, frame.render(format)).map_err(|source| Error::Io {
+        path: file.to_path_buf(),
+        source,
+    })
+}
+
+/// Guesses the format from the file's extension, falling back to plain text.
+fn format_for(file: &Path) -> render::Format {
+    match file.extension().and_then(|e| e.to_str()) {
+        Some("ppm") => render::Format::Ppm,
+        Some("svg") => render::Format::Svg,
+        _ => render::Format::Plain,
+    }
+}
+
+/// `out.svg` becomes `out-part1.svg`.
+fn part_file(file: &Path, part: u8) -> PathBuf {
+    let stem = file.file_stem().unwrap_or_default().to_string_lossy();
+    let name = match file.extension() {
+        Some(extension) => format!("{stem}-part{part}.{}", extension.to_string_lossy()),
+        None => format!("{stem}-part{part}"),
+    };
+    file.with_file_name(name)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn names_files_after_their_part_and_format() {
+        let file = part_file(Path::new("out/d6.svg"), 2);
+        assert_eq!(file, Path::new("out/d6-part2.svg"));
+        assert_eq!(format_for(&file), render::Format::Svg);
+
+        assert_eq!(part_file(Path::new("d6"), 1), Path::new("d6-part1"));
+        assert_eq!(format_for(Path::new("d6")), render::Format::Plain);
+    }
+}
@@ -0,0 +1,327 @@
+use crate::bench::seconds;
+use crate::days;
+use crate::error::Error;
+use crate::inputs::workspace_root;
+use crate::output::Document;
+use aoc_common::fixtures;
+use clap::Args;
+use serde::Deserialize;
+use std::collections::{BTreeMap, HashMap};
+use std::env;
+use std::fs;
+use std::path::{Path, PathBuf};
+use std::process::Command;
+use std::thread;
+use std::time::{Duration, SystemTime};
+
+#[derive(Args)]
+pub struct WatchArgs {
+    /// Day to watch.
+    #[arg(long)]
+    day: u8,
+
+    /// Part to run. Runs both parts if omitted.
+    #[arg(long, value_parser = clap::value_parser!(u8).range(1..=2))]
+    part: Option<u8>,
+
+    /// Input to run besides the fixtures. Defaults to the day's `data.txt`.
+    #[arg(long)]
+    input: Option<PathBuf>,
+
+    /// Seconds between checks for changes.
+    #[arg(long, default_value_t = 0.5, value_parser = seconds)]
+    interval: f64,
+}
+
+/// The answer to each part of each input, or why there isn't one.
+type Answers = BTreeMap<(PathBuf, u8), Result<String, String>>;
+
+/// One part as printed by `aoc run --format json`.
+#[derive(Deserialize)]
+struct RunResult {
+    part: u8,
+    answer: String,
+}
+
+/// Re-runs a day on its input and fixtures whenever they or the day's code change, until
+/// interrupted. Runs go through `cargo run` so that code changes are rebuilt first.
+pub fn watch(args: WatchArgs) -> Result<(), Error> {
+    let day = days::find(args.day).ok_or(Error::UnknownDay(args.day))?;
+    let root = workspace_root();
+    let crate_dir = root.join(format!("d{}", day.day));
+    let interval = Duration::from_secs_f64(args.interval);
+
+    let input = canonical(&args.input.unwrap_or_else(|| root.join(day.input)));
+
+    let mut previous = Answers::new();
+
+    loop {
+        // The fixtures are reloaded every time, as editing them is one of the changes
+        // being watched for
+        let (inputs, expected) = match load_fixtures(&crate_dir, args.part) {
+            Ok(expected) => {
+                let mut inputs = vec![input.clone()];
+                for (path, _) in expected.keys() {
+                    if !inputs.contains(path) {
+                        inputs.push(path.clone());
+                    }
+                }
+                (inputs, expected)
+            }
+            Err(err) => {
+                println!("error: {err}");
+                (vec![input.clone()], HashMap::new())
+            }
+        };
+
+        let mut watched = vec![
+            crate_dir.join("src"),
+            crate_dir.join("fixtures"),
+            root.join("common/src"),
+        ];
+        watched.extend(inputs.iter().cloned());
+        // Taken before running, so edits made during the run still trigger another one
+        let files = snapshot(&watched);
+
+        let mut answers = Answers::new();
+        for input in &inputs {
+            let parts = match args.part {
+                Some(part) => vec![part],
+                None => vec![1, 2],
+            };
+            match run_input(day.day, args.part, input) {
+                Ok(results) => {
+                    for result in results {
+                        answers.insert((input.clone(), result.part), Ok(result.answer));
+                    }
+                }
+                Err(message) => {
+                    for part in parts {
+                        answers.insert((input.clone(), part), Err(message.clone()));
+                    }
+                }
+            }
+        }
+
+        for ((input, part), answer) in &answers {
+            let key = (input.clone(), *part);
+            println!(
+                "{}",
+                report(
+                    input.strip_prefix(root).unwrap_or(input),
+                    *part,
+                    answer,
+                    previous.get(&key),
+                    expected.get(&key)
+                )
+            );
+        }
+        previous = answers;
+
+        // Wait for a change, then for the edits to settle before running again
+        let changed = loop {
+            thread::sleep(interval);
+            let current = snapshot(&watched);
+            if current != files {
+                thread::sleep(interval);
+                break changed_files(&files, &current);
+            }
+        };
+
+        println!();
+        for path in changed {
+            println!(
+                "changed {}",
+                path.strip_prefix(root).unwrap_or(&path).display()
+            );
+        }
+    }
+}
+
+/// The expected answers of the day's fixtures, keyed by input and part.
+fn load_fixtures(
+    crate_dir: &Path,
+    part: Option<u8>,
+) -> Result<HashMap<(PathBuf, u8), String>, Error> {
+    let fixtures = fixtures::load(crate_dir).map_err(|source| Error::Io {
+        path: crate_dir.join(fixtures::ANSWERS_FILE),
+        source,
+    })?;
+
+    Ok(fixtures
+        .into_iter()
+        .filter(|fixture| part.is_none_or(|part| part == fixture.part))
+        .map(|fixture| ((canonical(&fixture.input), fixture.part), fixture.answer))
+        .collect())
+}
+
+/// Solves `input` with the runner built from the current code.
+fn run_input(day: u8, part: Option<u8>, input: &Path) -> Result<Vec<RunResult>, String> {
+    let cargo = env::var_os("CARGO").unwrap_or_else(|| "cargo".into());
+    let mut command = Command::new(cargo);
+    command
+        .current_dir(workspace_root())
+        .args(["run", "--quiet", "--package", "aoc"]);
+    if !cfg!(debug_assertions) {
+        command.arg("--release");
+    }
+    command
+        .args(["--", "run", "--format", "json", "--day", &day.to_string()])
+        .arg("--input")
+        .arg(input);
+    if let Some(part) = part {
+        command.args(["--part", &part.to_string()]);
+    }
+
+    let output = command
+        .output()
+        .map_err(|err| format!("could not run cargo: {err}"))?;
+    if !output.status.success() {
+        return Err(String::from_utf8_lossy(&output.stderr).trim().to_string());
+    }
+
+    let document: Document<Vec<RunResult>> =
+        serde_json::from_slice(&output.stdout).map_err(|err| err.to_string())?;
+    Ok(document.results)
+}
+
+/// One line of the report: the answer, how it changed since the last run and whether it
+/// matches the fixtures.
+fn report(
+    input: &Path,
+    part: u8,
+    answer: &Result<String, String>,
+    previous: Option<&Result<String, String>>,
+    expected: Option<&String>,
+) -> String {
+    let answer = match answer {
+        Ok(answer) => answer,
+        Err(message) => {
+            let message = message.replace('\n', "\n    ");
+            return format!("{} part {part}: error\n    {message}", input.display());
+        }
+    };
+
+    let mut line = format!("{} part {part}: {answer}", input.display());
+    match previous {
+        Some(Ok(previous)) if previous != answer => line += &format!(" (was {previous})"),
+        Some(Err(_)) => line += " (was an error)",
+        _ => {}
+    }
+    match expected {
+        Some(expected) if expected == answer => line += ", ok",
+        Some(expected) => line += &format!(", expected {expected}"),
+        None => {}
+    }
+
+    line
+}
+
+fn canonical(path: &Path) -> PathBuf {
+    fs::canonicalize(path).unwrap_or_else(|_| path.to_path_buf())
+}
+
+/// The modification time of every file in or under `paths`. Missing paths are skipped, so
+/// deleting or creating a file also counts as a change.
+fn snapshot(paths: &[PathBuf]) -> BTreeMap<PathBuf, SystemTime> {
+    let mut files = BTreeMap::new();
+    let mut pending = paths.to_vec();
+
+    while let Some(path) = pending.pop() {
+        let Ok(metadata) = fs::metadata(&path) else {
+            continue;
+        };
+
+        if metadata.is_dir() {
+            if let Ok(entries) = fs::read_dir(&path) {
+                pending.extend(entries.flatten().map(|entry| entry.path()));
+            }
+        } else if let Ok(modified) = metadata.modified() {
+            files.insert(path, modified);
+        }
+    }
+
+    files
+}
+
+fn changed_files(
+    before: &BTreeMap<PathBuf, SystemTime>,
+    after: &BTreeMap<PathBuf, SystemTime>,
+) -> Vec<PathBuf> {
+    let mut changed: Vec<PathBuf> = after
+        .iter()
+        .filter(|(path, modified)| before.get(*path) != Some(modified))
+        .map(|(path, _)| path.clone())
+        .collect();
+    changed.extend(
+        before
+            .keys()
+            .filter(|path| !after.contains_key(*path))
+            .cloned(),
+    );
+    changed
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn ok(answer: &str) -> Result<String, String> {
+        Ok(answer.to_string())
+    }
+
+    #[test]
+    fn reports_changes_and_mismatches() {
+        let input = Path::new("d6/fixtures/example.txt");
+        let expected = "6".to_string();
+
+        assert_eq!(
+            report(input, 2, &ok("6"), None, Some(&expected)),
+            "d6/fixtures/example.txt part 2: 6, ok"
+        );
+        assert_eq!(
+            report(input, 2, &ok("5"), Some(&ok("6")), Some(&expected)),
+            "d6/fixtures/example.txt part 2: 5 (was 6), expected 6"
+        );
+        assert_eq!(
+            report(input, 2, &ok("5"), Some(&ok("5")), None),
+            "d6/fixtures/example.txt part 2: 5"
+        );
+        assert_eq!(
+            report(input, 1, &Err("error: boom\nmore".to_string()), None, None),
+            "d6/fixtures/example.txt part 1: error\n    error: boom\n    more"
+        );
+    }
+
+    #[test]
+    fn notices_new_changed_and_deleted_files() {
+        let dir = tempfile::tempdir().unwrap();
+        let kept = dir.path().join("kept.txt");
+        let edited = dir.path().join("nested/edited.txt");
+        let deleted = dir.path().join("deleted.txt");
+        fs::create_dir(dir.path().join("nested")).unwrap();
+        for path in [&kept, &edited, &deleted] {
+            fs::write(path, "before").unwrap();
+        }
+
+        let watched = vec![dir.path().to_path_buf()];
+        let before = snapshot(&watched);
+        assert_eq!(before.len(), 3);
+
+        let created = dir.path().join("created.txt");
+        fs::write(&created, "new").unwrap();
+        fs::remove_file(&deleted).unwrap();
+        fs::File::options()
+            .write(true)
+            .open(&edited)
+            .unwrap()
+            .set_modified(SystemTime::now() + Duration::from_secs(60))
+            .unwrap();
+
+        let mut changed = changed_files(&before, &snapshot(&watched));
+        changed.sort();
+        let mut expected = vec![created, deleted, edited];
+        expected.sort();
+        assert_eq!(changed, expected);
+    }
+}
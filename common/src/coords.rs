@@ -0,0 +1,112 @@
+use std::fmt::{self, Debug, Formatter};
+
+#[derive(Copy, Clone, Hash, Eq, PartialEq, Debug)]
+pub enum Direction {
+    Up,
+    Down,
+    Left,
+    Right,
+}
+
+impl Direction {
+    /// All four directions, clockwise starting from `Up`.
+    pub const ALL: [Direction; 4] = [
+        Direction::Up,
+        Direction::Right,
+        Direction::Down,
+        Direction::Left,
+    ];
+
+    pub fn turn_right(self) -> Direction {
+        match self {
+            Direction::Up => Direction::Right,
+            Direction::Right => Direction::Down,
+            Direction::Down => Direction::Left,
+            Direction::Left => Direction::Up,
+        }
+    }
+
+    pub fn turn_left(self) -> Direction {
+        match self {
+            Direction::Up => Direction::Left,
+            Direction::Left => Direction::Down,
+            Direction::Down => Direction::Right,
+            Direction::Right => Direction::Up,
+        }
+    }
+
+    pub fn opposite(self) -> Direction {
+        self.turn_right().turn_right()
+    }
+
+    /// The `(dx, dy)` offset of a single step, with `y` growing downwards.
+    pub fn offset(self) -> (isize, isize) {
+        match self {
+            Direction::Up => (0, -1),
+            Direction::Down => (0, 1),
+            Direction::Left => (-1, 0),
+            Direction::Right => (1, 0),
+        }
+    }
+
+    /// Parses the arrow characters used by the puzzles (`^`, `v`, `<`, `>`).
+    pub fn from_arrow(c: char) -> Option<Direction> {
+        match c {
+            '^' => Some(Direction::Up),
+            'v' => Some(Direction::Down),
+            '<' => Some(Direction::Left),
+            '>' => Some(Direction::Right),
+            _ => None,
+        }
+    }
+
+    /// The arrow character pointing this way, the inverse of `from_arrow`.
+    pub fn arrow(self) -> char {
+        match self {
+            Direction::Up => '^',
+            Direction::Down => 'v',
+            Direction::Left => '<',
+            Direction::Right => '>',
+        }
+    }
+}
+
+/// A position on a grid. `x` is the column and `y` the row, counted from the top left.
+#[derive(Copy, Clone, Hash, Eq, PartialEq, Ord, PartialOrd, Default)]
+pub struct Coords {
+    pub x: usize,
+    pub y: usize,
+}
+
+impl Coords {
+    pub const fn new(x: usize, y: usize) -> Self {
+        Self { x, y }
+    }
+
+    /// Moves by `(dx, dy)`, returning `None` if either component would become negative.
+    pub fn offset(self, dx: isize, dy: isize) -> Option<Coords> {
+        Some(Coords {
+            x: self.x.checked_add_signed(dx)?,
+            y: self.y.checked_add_signed(dy)?,
+        })
+    }
+
+    /// Takes a single step in `direction`. Bounds checking against a grid is left to
+    /// [`Grid::step`](crate::Grid::step).
+    pub fn step(self, direction: Direction) -> Option<Coords> {
+        let (dx, dy) = direction.offset();
+        self.offset(dx, dy)
+    }
+}
+
+impl Debug for Coords {
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        write!(f, "({}, {})", self.x, self.y)
+    }
+}
+
+impl From<(usize, usize)> for Coords {
+    fn from((x, y): (usize, usize)) -> Self {
+        Coords { x, y }
+    }
+}
@@ -0,0 +1,247 @@
+//! Differential testing: running every implementation of a part on the same inputs and
+//! shrinking any input they disagree on down to a minimal one.
+
+use crate::input::read_input;
+use crate::solution::{Solution, generate, implementations};
+use std::fs;
+use std::io;
+use std::ops::Range;
+use std::panic::{self, AssertUnwindSafe};
+use std::path::{Path, PathBuf};
+
+/// The directory, relative to a day's crate, holding inputs implementations once
+/// disagreed on.
+pub const REGRESSIONS_DIR: &str = "fixtures/regressions";
+
+/// What each implementation of a part answered for an input they don't agree on. A
+/// panicking implementation answers with its panic message.
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct Disagreement {
+    pub part: u8,
+    pub answers: Vec<(&'static str, Result<String, String>)>,
+}
+
+/// A generated input implementations disagreed on, after shrinking.
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct Counterexample {
+    pub seed: u64,
+    pub input: String,
+    pub disagreement: Disagreement,
+}
+
+/// Runs every implementation of `S` on `input`, returning the first part they disagree
+/// on. Inputs that don't parse can't be disagreed on.
+pub fn compare<S: Solution>(input: &str) -> Option<Disagreement> {
+    let model = S::parse(input).ok()?;
+    let implementations = implementations::<S>();
+
+    for part in [1, 2] {
+        let answers: Vec<_> = implementations
+            .iter()
+            .filter(|i| i.part == part)
+            .map(|i| {
+                let answer = panic::catch_unwind(AssertUnwindSafe(|| (i.run)(&model)))
+                    .map_err(|payload| panic_message(&*payload));
+                (i.name, answer)
+            })
+            .collect();
+
+        if answers.windows(2).any(|pair| pair[0].1 != pair[1].1) {
+            return Some(Disagreement { part, answers });
+        }
+    }
+
+    None
+}
+
+/// Compares the implementations on inputs generated from each seed, shrinking the first
+/// input they disagree on.
+pub fn search<S: Solution>(seeds: Range<u64>, scale: f64) -> Option<Counterexample> {
+    for seed in seeds {
+        let generated = generate::<S>(seed, scale)?;
+        let Some(disagreement) = compare::<S>(&generated.input) else {
+            continue;
+        };
+
+        let part = disagreement.part;
+        let input = shrink(&generated.input, |candidate| {
+            compare::<S>(candidate).is_some_and(|d| d.part == part)
+        });
+        let disagreement = compare::<S>(&input).expect("shrinking keeps the disagreement");
+
+        return Some(Counterexample {
+            seed,
+            input,
+            disagreement,
+        });
+    }
+
+    None
+}
+
+/// Makes `input` as small as possible while `fails` still holds, by removing runs of
+/// lines, then columns, then runs of characters, until none of those help any more.
+pub fn shrink(input: &str, fails: impl Fn(&str) -> bool) -> String {
+    let mut current = input.to_string();
+
+    loop {
+        let before = current.len();
+        current = remove_chunks(&current, split_lines, &fails);
+        current = remove_columns(&current, &fails);
+        current = remove_chunks(&current, split_chars, &fails);
+
+        if current.len() == before {
+            return current;
+        }
+    }
+}
+
+fn split_lines(input: &str) -> Vec<&str> {
+    input.split_inclusive('\n').collect()
+}
+
+fn split_chars(input: &str) -> Vec<&str> {
+    input
+        .char_indices()
+        .map(|(i, c)| &input[i..i + c.len_utf8()])
+        .collect()
+}
+
+// Tries removing runs of units, halving the run length down to single units.
+fn remove_chunks(
+    input: &str,
+    split: impl Fn(&str) -> Vec<&str>,
+    fails: &impl Fn(&str) -> bool,
+) -> String {
+    let mut units: Vec<String> = split(input).into_iter().map(String::from).collect();
+    let mut size = units.len().div_ceil(2);
+
+    while size > 0 {
+        let mut start = 0;
+        while start < units.len() {
+            let end = (start + size).min(units.len());
+            let candidate: String = units[..start].concat() + &units[end..].concat();
+
+            if fails(&candidate) {
+                units.drain(start..end);
+            } else {
+                start += size;
+            }
+        }
+        size /= 2;
+    }
+
+    units.concat()
+}
+
+// Tries removing each column of inputs whose lines are all the same length, like grids.
+fn remove_columns(input: &str, fails: &impl Fn(&str) -> bool) -> String {
+    let mut rows: Vec<Vec<char>> = input.lines().map(|l| l.chars().collect()).collect();
+    let width = rows.first().map_or(0, Vec::len);
+    if width < 2 || rows.iter().any(|row| row.len() != width) {
+        return input.to_string();
+    }
+
+    let join = |rows: &[Vec<char>]| -> String {
+        rows.iter()
+            .map(|row| row.iter().collect::<String>() + "\n")
+            .collect()
+    };
+
+    let mut column = width;
+    while column > 0 {
+        column -= 1;
+        let candidate: Vec<Vec<char>> = rows
+            .iter()
+            .map(|row| [&row[..column], &row[column + 1..]].concat())
+            .collect();
+
+        if !candidate[0].is_empty() && fails(&join(&candidate)) {
+            rows = candidate;
+        }
+    }
+
+    join(&rows)
+}
+
+fn panic_message(payload: &(dyn std::any::Any + Send)) -> String {
+    let message = payload
+        .downcast_ref::<&str>()
+        .map(|s| s.to_string())
+        .or_else(|| payload.downcast_ref::<String>().cloned())
+        .unwrap_or_else(|| "unknown panic".to_string());
+
+    format!("panicked: {message}")
+}
+
+/// Saves `input` as a regression fixture of the day in `crate_dir`, named after its
+/// contents so saving the same input twice keeps one file.
+pub fn save_regression(crate_dir: impl AsRef<Path>, input: &str) -> io::Result<PathBuf> {
+    let dir = crate_dir.as_ref().join(REGRESSIONS_DIR);
+    fs::create_dir_all(&dir)?;
+
+    let path = dir.join(format!("{:016x}.txt", fnv1a(input.as_bytes())));
+    fs::write(&path, input)?;
+    Ok(path)
+}
+
+// A hash that stays the same across Rust versions, unlike the standard library's.
+fn fnv1a(bytes: &[u8]) -> u64 {
+    bytes.iter().fold(0xcbf29ce484222325, |hash, byte| {
+        (hash ^ *byte as u64).wrapping_mul(0x100000001b3)
+    })
+}
+
+/// Panics unless every implementation agrees on the day's regression fixtures and on
+/// inputs generated from `seeds` at `scale`. Meant to be called from the tests of days
+/// with alternative implementations.
+pub fn assert_agree<S: Solution>(crate_dir: &str, seeds: Range<u64>, scale: f64) {
+    let mut regressions: Vec<PathBuf> =
+        match fs::read_dir(Path::new(crate_dir).join(REGRESSIONS_DIR)) {
+            Ok(entries) => entries.map(|entry| entry.unwrap().path()).collect(),
+            Err(err) if err.kind() == io::ErrorKind::NotFound => Vec::new(),
+            Err(err) => panic!("{crate_dir}/{REGRESSIONS_DIR}: {err}"),
+        };
+    regressions.sort();
+
+    for path in regressions {
+        let input = read_input(&path).unwrap_or_else(|err| panic!("{}: {err}", path.display()));
+        if let Some(disagreement) = compare::<S>(&input) {
+            panic!("{}: {disagreement:?}", path.display());
+        }
+    }
+
+    if let Some(counterexample) = search::<S>(seeds, scale) {
+        panic!(
+            "seed {} shrinks to {:?}: {:?}",
+            counterexample.seed, counterexample.input, counterexample.disagreement
+        );
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn shrinks_to_the_smallest_failing_input() {
+        let input = "abc\nxyz\nfoo bar\nqux\n";
+        let shrunk = shrink(input, |candidate| candidate.contains("o b"));
+        assert_eq!(shrunk, "o b");
+    }
+
+    #[test]
+    fn shrinks_grids_by_rows_and_columns() {
+        let input = "....\n.#..\n..#.\n....\n";
+        // Fails while the two walls are still diagonal neighbours
+        let shrunk = shrink(input, |candidate| {
+            let rows: Vec<&str> = candidate.lines().collect();
+            rows.windows(2).any(|pair| {
+                pair[0]
+                    .find('#')
+                    .is_some_and(|x| pair[1].find('#') == Some(x + 1))
+            }) && rows.iter().all(|row| row.len() == rows[0].len())
+        });
+        assert_eq!(shrunk, "#.\n.#");
+    }
+}
@@ -0,0 +1,58 @@
+use std::error::Error;
+use std::fmt::{self, Display, Formatter};
+use std::path::{Path, PathBuf};
+
+/// An input that could not be parsed, pointing at the offending token.
+///
+/// Lines and columns are 1-based, with columns counted in characters.
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct ParseError {
+    pub file: Option<PathBuf>,
+    pub line: usize,
+    pub column: usize,
+    pub token: String,
+    pub message: String,
+}
+
+impl ParseError {
+    pub fn new(line: usize, column: usize, token: &str, message: impl Into<String>) -> Self {
+        Self {
+            file: None,
+            line,
+            column,
+            token: token.to_string(),
+            message: message.into(),
+        }
+    }
+
+    /// An error for something missing from the input, reported just past its last character.
+    pub fn at_end(input: &str, message: impl Into<String>) -> Self {
+        let trimmed = input.trim_end();
+        let line = trimmed.lines().count().max(1);
+        let column = trimmed.lines().last().map_or(0, |l| l.chars().count()) + 1;
+
+        Self::new(line, column, "", message)
+    }
+
+    /// Records the file the input was read from.
+    pub fn with_file(mut self, file: impl AsRef<Path>) -> Self {
+        self.file = Some(file.as_ref().to_path_buf());
+        self
+    }
+}
+
+impl Display for ParseError {
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        match &self.file {
+            Some(file) => write!(f, "{}:", file.display())?,
+            None => write!(f, "<input>:")?,
+        }
+        write!(f, "{}:{}: {}", self.line, self.column, self.message)?;
+        if !self.token.is_empty() {
+            write!(f, " (found {:?})", self.token)?;
+        }
+        Ok(())
+    }
+}
+
+impl Error for ParseError {}
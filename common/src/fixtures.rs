@@ -0,0 +1,128 @@
+use crate::error::ParseError;
+use crate::input::{self, read_input};
+use crate::solution::{Solution, generate, solve};
+use std::io;
+use std::path::{Path, PathBuf};
+
+/// The file, relative to a day's crate, listing the answers we expect for its inputs.
+pub const ANSWERS_FILE: &str = "fixtures/answers.txt";
+
+/// An expected answer for one part of a puzzle input.
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct Fixture {
+    pub input: PathBuf,
+    pub part: u8,
+    pub answer: String,
+}
+
+impl Fixture {
+    pub fn read_input(&self) -> io::Result<String> {
+        read_input(&self.input)
+    }
+}
+
+/// Loads the fixtures of the day whose crate lives in `crate_dir`.
+///
+/// Each line of the answers file reads `<input> <part> <answer>`, with the input path
+/// relative to the crate. Blank lines and lines starting with `#` are ignored.
+pub fn load(crate_dir: impl AsRef<Path>) -> io::Result<Vec<Fixture>> {
+    let crate_dir = crate_dir.as_ref();
+    let path = crate_dir.join(ANSWERS_FILE);
+    let contents = read_input(&path)?;
+
+    parse(&contents, crate_dir)
+        .map_err(|err| io::Error::new(io::ErrorKind::InvalidData, err.with_file(&path)))
+}
+
+fn parse(contents: &str, crate_dir: &Path) -> Result<Vec<Fixture>, ParseError> {
+    let mut fixtures = Vec::new();
+
+    for line in input::numbered_lines(contents) {
+        if line.text.trim().is_empty() || line.text.starts_with('#') {
+            continue;
+        }
+
+        let tokens: Vec<_> = line.split_whitespace().collect();
+        let [input, part, answer] = tokens[..] else {
+            return Err(line.error(1, line.text, "expected `<input> <part> <answer>`"));
+        };
+
+        let part: u8 = part.parse()?;
+        if !(1..=2).contains(&part) {
+            return Err(tokens[1].error("part must be 1 or 2"));
+        }
+
+        fixtures.push(Fixture {
+            input: crate_dir.join(input.text),
+            part,
+            answer: answer.text.to_string(),
+        });
+    }
+
+    Ok(fixtures)
+}
+
+/// Solves every fixture of the day in `crate_dir` and panics listing all mismatches.
+/// Meant to be called from each day's tests.
+pub fn assert_answers<S: Solution>(crate_dir: &str) {
+    let fixtures = load(crate_dir).unwrap_or_else(|err| panic!("{err}"));
+    assert!(!fixtures.is_empty(), "no fixtures in {crate_dir}");
+
+    let mut failures = Vec::new();
+
+    for fixture in &fixtures {
+        let input = fixture
+            .read_input()
+            .unwrap_or_else(|err| panic!("{}: {err}", fixture.input.display()));
+
+        match solve::<S>(&input, fixture.part) {
+            Ok(Some(answer)) if answer == fixture.answer => {}
+            Ok(None) => failures.push(format!(
+                "{} part {}: the answer overflows",
+                fixture.input.display(),
+                fixture.part
+            )),
+            Ok(Some(answer)) => failures.push(format!(
+                "{} part {}: expected {}, got {answer}",
+                fixture.input.display(),
+                fixture.part,
+                fixture.answer
+            )),
+            Err(err) => failures.push(err.with_file(&fixture.input).to_string()),
+        }
+    }
+
+    assert!(failures.is_empty(), "\n{}", failures.join("\n"));
+}
+
+/// Seeds `assert_generated` checks.
+const GENERATED_SEEDS: u64 = 5;
+
+/// Solves inputs generated from a few seeds at `scale` and panics listing every answer
+/// that differs from the one the generator expected. Inputs without expected answers still
+/// have to parse and solve. Meant to be called from each day's tests.
+pub fn assert_generated<S: Solution>(scale: f64) {
+    let mut failures = Vec::new();
+
+    for seed in 0..GENERATED_SEEDS {
+        let generated = generate::<S>(seed, scale).expect("the day has no generator");
+        assert_eq!(
+            generate::<S>(seed, scale).as_ref(),
+            Some(&generated),
+            "seed {seed} generated two different inputs"
+        );
+
+        for part in 1..=2 {
+            match (solve::<S>(&generated.input, part), generated.answer(part)) {
+                (Ok(None), _) => failures.push(format!("seed {seed} part {part}: overflows")),
+                (Ok(Some(answer)), Some(expected)) if answer != expected => failures.push(format!(
+                    "seed {seed} part {part}: expected {expected}, got {answer}"
+                )),
+                (Ok(_), _) => {}
+                (Err(err), _) => failures.push(format!("seed {seed}: {err}")),
+            }
+        }
+    }
+
+    assert!(failures.is_empty(), "\n{}", failures.join("\n"));
+}
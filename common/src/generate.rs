@@ -0,0 +1,40 @@
+//! Random puzzle inputs, for testing and benchmarking beyond the one real input per day.
+
+pub use fastrand::Rng;
+
+/// A generated input, along with the answers when they are known from how the input was
+/// built rather than by solving it.
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct Generated {
+    pub input: String,
+    pub answers: [Option<String>; 2],
+}
+
+impl Generated {
+    /// An input whose answers are not known up front.
+    pub fn new(input: String) -> Self {
+        Self {
+            input,
+            answers: [None, None],
+        }
+    }
+
+    pub fn with_answer(mut self, part: u8, answer: impl ToString) -> Self {
+        self.answers[part as usize - 1] = Some(answer.to_string());
+        self
+    }
+
+    pub fn answer(&self, part: u8) -> Option<&str> {
+        self.answers[part as usize - 1].as_deref()
+    }
+}
+
+/// Scales a count from the real input, keeping at least one of whatever is counted.
+pub fn scaled(count: usize, scale: f64) -> usize {
+    ((count as f64 * scale).round() as usize).max(1)
+}
+
+/// Scales the side of a square grid from the real input so that its area grows with `scale`.
+pub fn scaled_side(side: usize, scale: f64) -> usize {
+    ((side as f64 * scale.sqrt()).round() as usize).max(1)
+}
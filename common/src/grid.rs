@@ -0,0 +1,163 @@
+use crate::coords::{Coords, Direction};
+use crate::error::ParseError;
+use crate::input;
+use std::fmt::{self, Debug, Formatter};
+use std::ops::{Index, IndexMut};
+
+/// A dense, row-major 2D grid.
+#[derive(Clone, Eq, PartialEq, Hash)]
+pub struct Grid<T> {
+    width: usize,
+    height: usize,
+    cells: Vec<T>,
+}
+
+impl<T> Grid<T> {
+    pub fn new(width: usize, height: usize, fill: T) -> Self
+    where
+        T: Clone,
+    {
+        Self {
+            width,
+            height,
+            cells: vec![fill; width * height],
+        }
+    }
+
+    /// Builds a grid from its rows. Panics if the rows have different lengths.
+    pub fn from_rows(rows: Vec<Vec<T>>) -> Self {
+        let height = rows.len();
+        let width = rows.first().map_or(0, Vec::len);
+
+        let mut cells = Vec::with_capacity(width * height);
+        for row in rows {
+            assert_eq!(row.len(), width, "grid rows must all have the same length");
+            cells.extend(row);
+        }
+
+        Self {
+            width,
+            height,
+            cells,
+        }
+    }
+
+    /// Parses a grid with one row per line, mapping each character through `f`. Characters
+    /// for which `f` returns `None` are rejected, as are rows of differing lengths.
+    pub fn parse(input: &str, mut f: impl FnMut(char) -> Option<T>) -> Result<Self, ParseError> {
+        let mut rows: Vec<Vec<T>> = Vec::new();
+
+        for line in input::numbered_lines(input) {
+            let mut row = Vec::new();
+            for (column, c) in line.chars() {
+                let cell =
+                    f(c).ok_or_else(|| line.error(column, &c.to_string(), "unexpected character"))?;
+                row.push(cell);
+            }
+
+            if let Some(first) = rows.first()
+                && first.len() != row.len()
+            {
+                return Err(line.error(
+                    row.len().min(first.len()) + 1,
+                    "",
+                    format!("expected {} columns, found {}", first.len(), row.len()),
+                ));
+            }
+            rows.push(row);
+        }
+
+        Ok(Self::from_rows(rows))
+    }
+
+    pub fn width(&self) -> usize {
+        self.width
+    }
+
+    pub fn height(&self) -> usize {
+        self.height
+    }
+
+    pub fn contains(&self, coords: Coords) -> bool {
+        coords.x < self.width && coords.y < self.height
+    }
+
+    pub fn get(&self, coords: Coords) -> Option<&T> {
+        if !self.contains(coords) {
+            return None;
+        }
+        self.cells.get(coords.y * self.width + coords.x)
+    }
+
+    pub fn get_mut(&mut self, coords: Coords) -> Option<&mut T> {
+        if !self.contains(coords) {
+            return None;
+        }
+        self.cells.get_mut(coords.y * self.width + coords.x)
+    }
+
+    pub fn row(&self, y: usize) -> &[T] {
+        &self.cells[y * self.width..(y + 1) * self.width]
+    }
+
+    pub fn rows(&self) -> impl Iterator<Item = &[T]> {
+        // `chunks` panics on a zero chunk size, which an empty grid would otherwise hit
+        self.cells.chunks(self.width.max(1))
+    }
+
+    /// Iterates over all coordinates in row-major order.
+    pub fn coords(&self) -> impl Iterator<Item = Coords> + use<T> {
+        let width = self.width;
+        (0..self.height).flat_map(move |y| (0..width).map(move |x| Coords { x, y }))
+    }
+
+    /// Iterates over all cells together with their coordinates, in row-major order.
+    pub fn iter(&self) -> impl Iterator<Item = (Coords, &T)> {
+        self.coords().zip(self.cells.iter())
+    }
+
+    /// Takes a single step from `coords`, returning `None` if it would leave the grid.
+    pub fn step(&self, coords: Coords, direction: Direction) -> Option<Coords> {
+        coords.step(direction).filter(|next| self.contains(*next))
+    }
+
+    /// The orthogonal neighbours of `coords` that lie inside the grid.
+    pub fn neighbours(&self, coords: Coords) -> impl Iterator<Item = Coords> + '_ {
+        Direction::ALL
+            .into_iter()
+            .filter_map(move |direction| self.step(coords, direction))
+    }
+
+    pub fn map<U>(&self, f: impl FnMut(&T) -> U) -> Grid<U> {
+        Grid {
+            width: self.width,
+            height: self.height,
+            cells: self.cells.iter().map(f).collect(),
+        }
+    }
+}
+
+impl<T> Index<Coords> for Grid<T> {
+    type Output = T;
+
+    fn index(&self, coords: Coords) -> &T {
+        assert!(self.contains(coords), "{coords:?} is outside the grid");
+        &self.cells[coords.y * self.width + coords.x]
+    }
+}
+
+impl<T> IndexMut<Coords> for Grid<T> {
+    fn index_mut(&mut self, coords: Coords) -> &mut T {
+        assert!(self.contains(coords), "{coords:?} is outside the grid");
+        &mut self.cells[coords.y * self.width + coords.x]
+    }
+}
+
+impl<T: Debug> Debug for Grid<T> {
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        for row in self.rows() {
+            writeln!(f, "{row:?}")?;
+        }
+        Ok(())
+    }
+}
@@ -0,0 +1,165 @@
+use crate::error::ParseError;
+use std::fmt::Display;
+use std::fs::File;
+use std::io::{self, Read};
+use std::path::Path;
+use std::str::FromStr;
+
+/// Reads a puzzle input into memory.
+pub fn read_input(path: impl AsRef<Path>) -> io::Result<String> {
+    let mut file = File::open(path)?;
+    let mut contents = String::new();
+
+    file.read_to_string(&mut contents)?;
+    Ok(contents)
+}
+
+/// Returns the lines of the input, ignoring a trailing newline or blank lines at the end of
+/// the file.
+pub fn lines(input: &str) -> impl Iterator<Item = &str> {
+    input.trim_end().lines()
+}
+
+/// Like [`lines`], but keeps track of line numbers for error reporting.
+pub fn numbered_lines(input: &str) -> impl Iterator<Item = Line<'_>> {
+    lines(input).enumerate().map(|(i, text)| Line {
+        number: i + 1,
+        text,
+    })
+}
+
+/// A line of input together with its 1-based line number.
+#[derive(Copy, Clone, Debug)]
+pub struct Line<'a> {
+    pub number: usize,
+    pub text: &'a str,
+}
+
+impl<'a> Line<'a> {
+    /// Splits the line on `separator`, keeping empty tokens.
+    pub fn split(self, separator: char) -> impl Iterator<Item = Token<'a>> {
+        let mut offset = 0;
+        self.text.split(separator).map(move |text| {
+            let token = self.token_at(offset, text);
+            offset += text.len() + separator.len_utf8();
+            token
+        })
+    }
+
+    pub fn split_whitespace(self) -> impl Iterator<Item = Token<'a>> {
+        self.text.split_whitespace().map(move |text| {
+            let offset = text.as_ptr() as usize - self.text.as_ptr() as usize;
+            self.token_at(offset, text)
+        })
+    }
+
+    /// Splits the line at the first `separator`, failing if there is none.
+    pub fn split_once(self, separator: char) -> Result<(Token<'a>, Token<'a>), ParseError> {
+        let Some((a, b)) = self.text.split_once(separator) else {
+            return Err(self.error(
+                self.text.chars().count() + 1,
+                "",
+                format!("expected {separator:?}"),
+            ));
+        };
+
+        Ok((
+            self.token_at(0, a),
+            self.token_at(a.len() + separator.len_utf8(), b),
+        ))
+    }
+
+    /// The line's characters with their 1-based columns.
+    pub fn chars(self) -> impl Iterator<Item = (usize, char)> + 'a {
+        self.text.chars().enumerate().map(|(i, c)| (i + 1, c))
+    }
+
+    pub fn error(self, column: usize, token: &str, message: impl Into<String>) -> ParseError {
+        ParseError::new(self.number, column, token, message)
+    }
+
+    fn token_at(self, offset: usize, text: &'a str) -> Token<'a> {
+        Token {
+            text,
+            line: self.number,
+            column: self.text[..offset].chars().count() + 1,
+        }
+    }
+}
+
+/// A slice of a line, remembering where it came from.
+#[derive(Copy, Clone, Debug)]
+pub struct Token<'a> {
+    pub text: &'a str,
+    pub line: usize,
+    pub column: usize,
+}
+
+impl<'a> Token<'a> {
+    pub fn split_whitespace(self) -> impl Iterator<Item = Token<'a>> {
+        self.text.split_whitespace().map(move |text| {
+            let offset = text.as_ptr() as usize - self.text.as_ptr() as usize;
+            Token {
+                text,
+                line: self.line,
+                column: self.column + self.text[..offset].chars().count(),
+            }
+        })
+    }
+
+    pub fn parse<T>(&self) -> Result<T, ParseError>
+    where
+        T: FromStr,
+        T::Err: Display,
+    {
+        self.text.parse().map_err(|err| self.error(err))
+    }
+
+    pub fn error(&self, message: impl Display) -> ParseError {
+        ParseError::new(self.line, self.column, self.text, message.to_string())
+    }
+}
+
+/// Parses a single decimal digit.
+pub fn digit(line: Line, column: usize, c: char) -> Result<u32, ParseError> {
+    c.to_digit(10)
+        .ok_or_else(|| line.error(column, &c.to_string(), "expected a digit"))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn tokens_know_their_columns() {
+        let line = numbered_lines("1 2\n34   5  x\n").nth(1).unwrap();
+
+        let tokens: Vec<_> = line
+            .split_whitespace()
+            .map(|t| (t.text, t.column))
+            .collect();
+        assert_eq!(tokens, [("34", 1), ("5", 6), ("x", 9)]);
+
+        let err = line.split_whitespace().last().unwrap().parse::<i32>();
+        assert_eq!(
+            err.unwrap_err().to_string(),
+            r#"<input>:2:9: invalid digit found in string (found "x")"#
+        );
+    }
+
+    #[test]
+    fn split_once_points_past_the_line_when_missing() {
+        let line = numbered_lines("12 3").next().unwrap();
+        let err = line.split_once(':').unwrap_err();
+
+        assert_eq!((err.line, err.column), (1, 5));
+
+        let (a, b) = numbered_lines("12: 3")
+            .next()
+            .unwrap()
+            .split_once(':')
+            .unwrap();
+        let b: Vec<_> = b.split_whitespace().map(|t| t.column).collect();
+        assert_eq!((a.column, b), (1, vec![5]));
+    }
+}
@@ -0,0 +1,14 @@
+pub mod coords;
+pub mod differential;
+pub mod error;
+pub mod fixtures;
+pub mod generate;
+pub mod grid;
+pub mod input;
+pub mod render;
+pub mod solution;
+
+pub use coords::{Coords, Direction};
+pub use error::ParseError;
+pub use grid::Grid;
+pub use solution::Solution;
@@ -0,0 +1,375 @@
+//! Drawing grids with overlays, either for the terminal or as PPM and SVG images.
+
+use crate::coords::Coords;
+use crate::grid::Grid;
+use std::fmt::Write;
+
+#[derive(Copy, Clone, Debug, Eq, PartialEq, Hash)]
+pub struct Rgb(pub u8, pub u8, pub u8);
+
+impl Rgb {
+    pub const BLACK: Rgb = Rgb(0x11, 0x11, 0x11);
+    pub const GREY: Rgb = Rgb(0x80, 0x80, 0x80);
+    pub const WHITE: Rgb = Rgb(0xee, 0xee, 0xee);
+    pub const RED: Rgb = Rgb(0xe0, 0x40, 0x40);
+    pub const GREEN: Rgb = Rgb(0x40, 0xc0, 0x50);
+    pub const BLUE: Rgb = Rgb(0x40, 0x70, 0xe0);
+    pub const YELLOW: Rgb = Rgb(0xf0, 0xc0, 0x30);
+    pub const MAGENTA: Rgb = Rgb(0xc0, 0x50, 0xc0);
+    pub const CYAN: Rgb = Rgb(0x40, 0xc0, 0xc0);
+
+    const PALETTE: [Rgb; 6] = [
+        Rgb::RED,
+        Rgb::GREEN,
+        Rgb::BLUE,
+        Rgb::YELLOW,
+        Rgb::MAGENTA,
+        Rgb::CYAN,
+    ];
+
+    /// A colour from a small fixed palette, for telling apart things like antenna
+    /// frequencies or file ids. Indices past the palette wrap around.
+    pub fn palette(index: usize) -> Rgb {
+        Self::PALETTE[index % Self::PALETTE.len()]
+    }
+
+    /// The colour a fraction `t` of the way from `self` to `other`, with `t` clamped to
+    /// `0.0..=1.0`.
+    pub fn blend(self, other: Rgb, t: f32) -> Rgb {
+        let t = t.clamp(0.0, 1.0);
+        let mix = |a: u8, b: u8| (a as f32 + (b as f32 - a as f32) * t).round() as u8;
+        Rgb(
+            mix(self.0, other.0),
+            mix(self.1, other.1),
+            mix(self.2, other.2),
+        )
+    }
+
+    fn hex(self) -> String {
+        format!("#{:02x}{:02x}{:02x}", self.0, self.1, self.2)
+    }
+}
+
+/// One drawn grid cell. Cells without colours use the renderer's defaults.
+#[derive(Copy, Clone, Debug, Eq, PartialEq)]
+pub struct Cell {
+    pub glyph: char,
+    pub fg: Option<Rgb>,
+    pub bg: Option<Rgb>,
+}
+
+#[derive(Copy, Clone, Debug, Eq, PartialEq)]
+pub enum Format {
+    /// Text coloured with 24-bit ANSI escape codes.
+    Ansi,
+    /// Text without colours; highlights only show up through their glyphs.
+    Plain,
+    /// A binary PPM image with one block of pixels per cell. Glyphs and labels are not
+    /// drawn, only colours.
+    Ppm,
+    Svg,
+}
+
+/// A grid ready to be drawn, along with the overlays drawn on top of it.
+#[derive(Clone, Debug)]
+pub struct Frame {
+    cells: Grid<Cell>,
+    paths: Vec<(Vec<Coords>, Rgb)>,
+    labels: Vec<(Coords, String, Rgb)>,
+    title: Option<String>,
+}
+
+/// Side of a cell, in pixels, in PPM images.
+const PPM_CELL: usize = 4;
+/// Side of a cell, in pixels, in SVG images.
+const SVG_CELL: usize = 16;
+
+impl Frame {
+    /// A frame showing `grid`, with each cell drawn as the glyph `glyph` returns for it.
+    pub fn new<T>(grid: &Grid<T>, glyph: impl Fn(&T) -> char) -> Self {
+        Self {
+            cells: grid.map(|value| Cell {
+                glyph: glyph(value),
+                fg: None,
+                bg: None,
+            }),
+            paths: Vec::new(),
+            labels: Vec::new(),
+            title: None,
+        }
+    }
+
+    pub fn width(&self) -> usize {
+        self.cells.width()
+    }
+
+    pub fn height(&self) -> usize {
+        self.cells.height()
+    }
+
+    pub fn set_title(&mut self, title: impl Into<String>) {
+        self.title = Some(title.into());
+    }
+
+    /// Gives direct access to a cell, e.g. to change its glyph.
+    pub fn cell_mut(&mut self, coords: Coords) -> &mut Cell {
+        &mut self.cells[coords]
+    }
+
+    /// Draws the glyph at `coords` in `color`.
+    pub fn color(&mut self, coords: Coords, color: Rgb) {
+        self.cells[coords].fg = Some(color);
+    }
+
+    /// Fills the background of the cell at `coords` with `color`.
+    pub fn highlight(&mut self, coords: Coords, color: Rgb) {
+        self.cells[coords].bg = Some(color);
+    }
+
+    /// Highlights every cell along `path`, which images also join up with a line.
+    pub fn path(&mut self, path: impl IntoIterator<Item = Coords>, color: Rgb) {
+        let path: Vec<Coords> = path.into_iter().collect();
+        for coords in &path {
+            self.highlight(*coords, color);
+        }
+        self.paths.push((path, color));
+    }
+
+    /// Writes `text` over the cells starting at `coords` and running right, cut off at the
+    /// edge of the grid.
+    pub fn label(&mut self, coords: Coords, text: impl Into<String>, color: Rgb) {
+        self.labels.push((coords, text.into(), color));
+    }
+
+    pub fn render(&self, format: Format) -> Vec<u8> {
+        match format {
+            Format::Ansi => self.render_text(true).into_bytes(),
+            Format::Plain => self.render_text(false).into_bytes(),
+            Format::Ppm => self.render_ppm(),
+            Format::Svg => self.render_svg().into_bytes(),
+        }
+    }
+
+    /// The cells with the labels written over them.
+    fn labelled_cells(&self) -> Grid<Cell> {
+        let mut cells = self.cells.clone();
+
+        for (start, text, color) in &self.labels {
+            for (i, glyph) in text.chars().enumerate() {
+                let Some(cell) = start.offset(i as isize, 0).and_then(|c| cells.get_mut(c)) else {
+                    break;
+                };
+                cell.glyph = glyph;
+                cell.fg = Some(*color);
+            }
+        }
+
+        cells
+    }
+
+    fn render_text(&self, ansi: bool) -> String {
+        let mut out = String::new();
+        if let Some(title) = &self.title {
+            if ansi {
+                writeln!(out, "\x1b[1m{title}\x1b[0m").unwrap();
+            } else {
+                writeln!(out, "{title}").unwrap();
+            }
+        }
+
+        for row in self.labelled_cells().rows() {
+            // Only emit escape codes when the style changes, which keeps large grids
+            // reasonably small
+            let mut style = (None, None);
+            for cell in row {
+                if ansi && (cell.fg, cell.bg) != style {
+                    if style != (None, None) {
+                        out.push_str("\x1b[0m");
+                    }
+                    if let Some(Rgb(r, g, b)) = cell.fg {
+                        write!(out, "\x1b[38;2;{r};{g};{b}m").unwrap();
+                    }
+                    if let Some(Rgb(r, g, b)) = cell.bg {
+                        write!(out, "\x1b[48;2;{r};{g};{b}m").unwrap();
+                    }
+                    style = (cell.fg, cell.bg);
+                }
+                out.push(cell.glyph);
+            }
+            if ansi && style != (None, None) {
+                out.push_str("\x1b[0m");
+            }
+            out.push('\n');
+        }
+
+        out
+    }
+
+    fn render_ppm(&self) -> Vec<u8> {
+        let (width, height) = (self.width() * PPM_CELL, self.height() * PPM_CELL);
+        let mut out = format!("P6\n{width} {height}\n255\n").into_bytes();
+
+        for row in self.cells.rows() {
+            let pixels: Vec<u8> = row
+                .iter()
+                .flat_map(|cell| {
+                    let Rgb(r, g, b) = pixel(cell);
+                    [r, g, b].repeat(PPM_CELL)
+                })
+                .collect();
+            for _ in 0..PPM_CELL {
+                out.extend_from_slice(&pixels);
+            }
+        }
+
+        out
+    }
+
+    fn render_svg(&self) -> String {
+        let (width, height) = (self.width() * SVG_CELL, self.height() * SVG_CELL);
+        let centre = |c: Coords| (c.x * SVG_CELL + SVG_CELL / 2, c.y * SVG_CELL + SVG_CELL / 2);
+
+        let mut out = String::new();
+        writeln!(
+            out,
+            r#"<svg xmlns="http://www.w3.org/2000/svg" width="{width}" height="{height}" viewBox="0 0 {width} {height}" font-family="monospace" font-size="{}" text-anchor="middle" dominant-baseline="central">"#,
+            SVG_CELL * 3 / 4
+        )
+        .unwrap();
+        if let Some(title) = &self.title {
+            writeln!(out, "<title>{}</title>", escape(title)).unwrap();
+        }
+        writeln!(
+            out,
+            r#"<rect width="{width}" height="{height}" fill="{}"/>"#,
+            Rgb::BLACK.hex()
+        )
+        .unwrap();
+
+        for (coords, cell) in self.cells.iter() {
+            if let Some(bg) = cell.bg {
+                writeln!(
+                    out,
+                    r#"<rect x="{}" y="{}" width="{SVG_CELL}" height="{SVG_CELL}" fill="{}"/>"#,
+                    coords.x * SVG_CELL,
+                    coords.y * SVG_CELL,
+                    bg.hex()
+                )
+                .unwrap();
+            }
+        }
+
+        for (path, color) in &self.paths {
+            let points: Vec<String> = path
+                .iter()
+                .map(|c| {
+                    let (x, y) = centre(*c);
+                    format!("{x},{y}")
+                })
+                .collect();
+            writeln!(
+                out,
+                r#"<polyline points="{}" fill="none" stroke="{}" stroke-width="2" stroke-opacity="0.8"/>"#,
+                points.join(" "),
+                color.blend(Rgb::WHITE, 0.5).hex()
+            )
+            .unwrap();
+        }
+
+        for (coords, cell) in self.labelled_cells().iter() {
+            if cell.glyph == ' ' || cell.glyph == '.' && cell.fg.is_none() {
+                continue;
+            }
+            let (x, y) = centre(coords);
+            writeln!(
+                out,
+                r#"<text x="{x}" y="{y}" fill="{}">{}</text>"#,
+                cell.fg.unwrap_or(Rgb::WHITE).hex(),
+                escape(&cell.glyph.to_string())
+            )
+            .unwrap();
+        }
+
+        out.push_str("</svg>\n");
+        out
+    }
+}
+
+/// The colour of a cell in images that cannot draw its glyph.
+fn pixel(cell: &Cell) -> Rgb {
+    match (cell.bg, cell.fg, cell.glyph) {
+        (Some(bg), _, _) => bg,
+        (None, Some(fg), _) => fg,
+        (None, None, ' ' | '.') => Rgb::BLACK,
+        (None, None, _) => Rgb::GREY,
+    }
+}
+
+fn escape(text: &str) -> String {
+    text.replace('&', "&amp;")
+        .replace('<', "&lt;")
+        .replace('>', "&gt;")
+        .replace('"', "&quot;")
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn frame() -> Frame {
+        let grid = Grid::parse("#..\n.#.\n", |c| Some(c == '#')).unwrap();
+        Frame::new(&grid, |wall| if *wall { '#' } else { '.' })
+    }
+
+    #[test]
+    fn plain_text_shows_labels_but_not_colours() {
+        let mut frame = frame();
+        frame.set_title("example");
+        frame.highlight(Coords::new(1, 0), Rgb::RED);
+        frame.label(Coords::new(1, 1), "AB", Rgb::GREEN);
+
+        let text = String::from_utf8(frame.render(Format::Plain)).unwrap();
+        assert_eq!(text, "example\n#..\n.AB\n");
+    }
+
+    #[test]
+    fn ansi_text_switches_colours_per_run() {
+        let mut frame = frame();
+        frame.path([Coords::new(1, 0), Coords::new(2, 0)], Rgb::BLUE);
+
+        let text = String::from_utf8(frame.render(Format::Ansi)).unwrap();
+        assert_eq!(text, "#\x1b[48;2;64;112;224m..\x1b[0m\n.#.\n".to_string());
+    }
+
+    #[test]
+    fn ppm_has_a_block_of_pixels_per_cell() {
+        let mut frame = frame();
+        frame.highlight(Coords::new(2, 1), Rgb::RED);
+
+        let image = frame.render(Format::Ppm);
+        let header = format!("P6\n{} {}\n255\n", 3 * PPM_CELL, 2 * PPM_CELL);
+        assert!(image.starts_with(header.as_bytes()));
+
+        let pixels = &image[header.len()..];
+        assert_eq!(pixels.len(), 3 * 2 * PPM_CELL * PPM_CELL * 3);
+        // The last pixel belongs to the highlighted cell
+        assert_eq!(
+            pixels[pixels.len() - 3..],
+            [Rgb::RED.0, Rgb::RED.1, Rgb::RED.2]
+        );
+    }
+
+    #[test]
+    fn svg_draws_overlays() {
+        let mut frame = frame();
+        frame.set_title("a < b");
+        frame.path([Coords::new(0, 1), Coords::new(1, 1)], Rgb::BLUE);
+        frame.label(Coords::new(2, 0), "&", Rgb::GREEN);
+
+        let svg = String::from_utf8(frame.render(Format::Svg)).unwrap();
+        assert!(svg.contains("<title>a &lt; b</title>"));
+        assert!(svg.contains(r#"<polyline points="8,24 24,24""#));
+        assert!(svg.contains(r##"<text x="40" y="8" fill="#40c050">&amp;</text>"##));
+        assert!(svg.ends_with("</svg>\n"));
+    }
+}
@@ -0,0 +1,117 @@
+use crate::error::ParseError;
+use crate::generate::{Generated, Rng};
+use crate::render::Frame;
+
+/// A solved puzzle day.
+///
+/// The input is parsed once into a typed model, which both parts then work from.
+pub trait Solution {
+    const DAY: u8;
+
+    type Model;
+    type Answer1: Answer;
+    type Answer2: Answer;
+
+    fn parse(input: &str) -> Result<Self::Model, ParseError>;
+
+    fn part_1(model: &Self::Model) -> Self::Answer1;
+
+    fn part_2(model: &Self::Model) -> Self::Answer2;
+
+    /// Other ways of solving either part, which benchmarks run alongside `part_1` and
+    /// `part_2` for comparison.
+    fn alternatives() -> Vec<Implementation<Self::Model>> {
+        Vec::new()
+    }
+
+    /// Generates a random input roughly `scale` times the size of the real one, for days
+    /// that support it.
+    fn generate(_rng: &mut Rng, _scale: f64) -> Option<Generated> {
+        None
+    }
+
+    /// Draws how a part is solved, for days that support it.
+    fn visualize(_model: &Self::Model, _part: u8) -> Option<Frame> {
+        None
+    }
+}
+
+/// What implementations answer with when the answer overflows the integers they work with.
+pub const OVERFLOW: &str = "overflow";
+
+/// Something a part can answer with.
+pub trait Answer {
+    /// The answer as it would be submitted, or `None` if it overflowed.
+    fn checked(&self) -> Option<String>;
+}
+
+macro_rules! answer {
+    ($($t:ty),*) => {
+        $(impl Answer for $t {
+            fn checked(&self) -> Option<String> {
+                Some(self.to_string())
+            }
+        })*
+    };
+}
+
+answer!(i32, i64, u32, u64, usize, String);
+
+/// An answer that is `None` when it overflows.
+impl<T: Answer> Answer for Option<T> {
+    fn checked(&self) -> Option<String> {
+        self.as_ref()?.checked()
+    }
+}
+
+/// A named way of solving one part of a puzzle.
+pub struct Implementation<M> {
+    pub name: &'static str,
+    pub part: u8,
+    pub run: fn(&M) -> String,
+}
+
+impl<M> Implementation<M> {
+    pub fn new(name: &'static str, part: u8, run: fn(&M) -> String) -> Self {
+        Self { name, part, run }
+    }
+}
+
+/// Every implementation of a solution: `part_1` and `part_2`, named `default`, followed by
+/// its alternatives.
+pub fn implementations<S: Solution>() -> Vec<Implementation<S::Model>> {
+    let mut implementations = vec![
+        Implementation::new("default", 1, |model| unchecked(S::part_1(model))),
+        Implementation::new("default", 2, |model| unchecked(S::part_2(model))),
+    ];
+    implementations.extend(S::alternatives());
+    implementations
+}
+
+/// Formats an answer, standing in [`OVERFLOW`] for one that overflowed.
+pub fn unchecked(answer: impl Answer) -> String {
+    answer.checked().unwrap_or_else(|| OVERFLOW.to_string())
+}
+
+/// Parses `input` and solves one part of it, formatting the answer. The answer is `None`
+/// if it overflowed.
+pub fn solve<S: Solution>(input: &str, part: u8) -> Result<Option<String>, ParseError> {
+    let model = S::parse(input)?;
+
+    match part {
+        1 => Ok(S::part_1(&model).checked()),
+        2 => Ok(S::part_2(&model).checked()),
+        _ => panic!("invalid part {part}"),
+    }
+}
+
+/// Parses `input` and draws how one part of it is solved, if the day supports that.
+pub fn visualize<S: Solution>(input: &str, part: u8) -> Result<Option<Frame>, ParseError> {
+    let model = S::parse(input)?;
+    Ok(S::visualize(&model, part))
+}
+
+/// Generates an input for `S` from `seed`. The same seed and scale always give the same input.
+pub fn generate<S: Solution>(seed: u64, scale: f64) -> Option<Generated> {
+    S::generate(&mut Rng::with_seed(seed), scale)
+}
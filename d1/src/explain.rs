@@ -0,0 +1,87 @@
+//! How each entry of the lists adds up to the totals.
+
+use serde::Serialize;
+use std::collections::HashMap;
+
+/// One pair of the sorted lists, with what it adds to the distance and what its left id
+/// adds to the similarity.
+#[derive(Clone, Debug, Eq, PartialEq, Serialize)]
+pub struct Pair {
+    /// 1-based position of the pair in the sorted lists.
+    pub rank: usize,
+    pub left: i64,
+    pub right: i64,
+    pub distance: i64,
+    /// Times the left id appears in the right list.
+    pub occurrences: usize,
+    pub similarity: i64,
+}
+
+/// Breaks both totals down by pair, or gives `None` if a pair's distance or similarity
+/// doesn't fit in i64. Both lists must be sorted.
+pub fn explain(list1: &[i64], list2: &[i64]) -> Option<Vec<Pair>> {
+    let mut counts: HashMap<i64, usize> = HashMap::new();
+    for id in list2 {
+        *counts.entry(*id).or_default() += 1;
+    }
+
+    list1
+        .iter()
+        .zip(list2)
+        .enumerate()
+        .map(|(i, (&left, &right))| {
+            let occurrences = counts.get(&left).copied().unwrap_or(0);
+            Some(Pair {
+                rank: i + 1,
+                left,
+                right,
+                distance: left.checked_sub(right)?.checked_abs()?,
+                occurrences,
+                similarity: left.checked_mul(i64::try_from(occurrences).ok()?)?,
+            })
+        })
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::Day1;
+    use aoc_common::Solution;
+
+    #[test]
+    fn pairs_add_up_to_the_answers() {
+        let input = include_str!("../fixtures/example.txt");
+        let model = Day1::parse(input).unwrap();
+        let pairs = explain(&model.0, &model.1).unwrap();
+
+        assert_eq!(
+            pairs[0],
+            Pair {
+                rank: 1,
+                left: 1,
+                right: 3,
+                distance: 2,
+                occurrences: 0,
+                similarity: 0,
+            }
+        );
+        assert_eq!(
+            Some(pairs.iter().map(|p| p.distance).sum::<i64>()),
+            Day1::part_1(&model)
+        );
+        assert_eq!(
+            Some(pairs.iter().map(|p| p.similarity).sum::<i64>()),
+            Day1::part_2(&model)
+        );
+    }
+
+    #[test]
+    fn overflowing_pairs_are_none() {
+        assert_eq!(explain(&[i64::MAX], &[i64::MIN]), None);
+        assert_eq!(
+            explain(&[i64::MAX / 2 + 1; 2], &[i64::MAX / 2 + 1; 2]),
+            None
+        );
+    }
+}
@@ -0,0 +1,361 @@
+//! Sorting the location lists on disk, for inputs too large to hold in memory.
+//!
+//! Each list is cut into runs that are sorted in memory and spilled to temporary files,
+//! which are then merged back into one sorted stream per list. Both totals are worked out
+//! from those streams without ever holding a whole list.
+
+use crate::parse_line;
+use aoc_common::ParseError;
+use aoc_common::input::Line;
+use std::cmp::Reverse;
+use std::collections::BinaryHeap;
+use std::error;
+use std::fmt::{self, Display, Formatter};
+use std::fs::File;
+use std::io::{self, BufRead, BufReader, BufWriter, Read, Seek, Write};
+use std::path::PathBuf;
+
+/// How much of the input to hold in memory at once, and where to put the rest.
+#[derive(Clone, Debug)]
+pub struct ExternalSort {
+    /// Numbers of each list sorted in memory before being spilled to disk.
+    pub run_len: usize,
+    /// Most runs merged at once. Once there are this many runs of the same length they
+    /// are merged into a single longer one.
+    pub fan_in: usize,
+    /// Where to spill runs. Defaults to the system's temporary directory.
+    pub temp_dir: Option<PathBuf>,
+}
+
+impl Default for ExternalSort {
+    fn default() -> Self {
+        Self {
+            run_len: 1 << 20,
+            fan_in: 64,
+            temp_dir: None,
+        }
+    }
+}
+
+/// Both answers for a pair of location lists.
+#[derive(Clone, Copy, Debug, Default, Eq, PartialEq)]
+pub struct Totals {
+    pub distance: i64,
+    pub similarity: i64,
+}
+
+#[derive(Debug)]
+pub enum Error {
+    Io(io::Error),
+    Parse(ParseError),
+    /// A total doesn't fit in an `i64`.
+    Overflow,
+}
+
+impl Display for Error {
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        match self {
+            Error::Io(err) => write!(f, "{err}"),
+            Error::Parse(err) => write!(f, "{err}"),
+            Error::Overflow => write!(f, "the totals don't fit in 64 bits"),
+        }
+    }
+}
+
+impl error::Error for Error {
+    fn source(&self) -> Option<&(dyn error::Error + 'static)> {
+        match self {
+            Error::Io(err) => Some(err),
+            Error::Parse(err) => Some(err),
+            Error::Overflow => None,
+        }
+    }
+}
+
+impl From<io::Error> for Error {
+    fn from(err: io::Error) -> Self {
+        Error::Io(err)
+    }
+}
+
+impl From<ParseError> for Error {
+    fn from(err: ParseError) -> Self {
+        Error::Parse(err)
+    }
+}
+
+impl ExternalSort {
+    /// Reads both lists from `reader` and works out their totals, holding at most
+    /// `run_len` numbers of each list in memory.
+    pub fn totals(&self, reader: impl BufRead) -> Result<Totals, Error> {
+        let mut left = Column::new(self);
+        let mut right = Column::new(self);
+
+        // Blank lines are fine at the end of the input but nowhere else, so the first one
+        // is kept until we know which
+        let mut blank: Option<(usize, String)> = None;
+
+        for (i, text) in reader.lines().enumerate() {
+            let text = text?;
+            if text.trim().is_empty() {
+                blank.get_or_insert((i + 1, text));
+                continue;
+            }
+            if let Some((number, text)) = blank.take() {
+                parse_line(Line {
+                    number,
+                    text: &text,
+                })?;
+            }
+
+            let (a, b) = parse_line(Line {
+                number: i + 1,
+                text: &text,
+            })?;
+            left.push(a)?;
+            right.push(b)?;
+        }
+
+        let (left, right) = (left.finish(), right.finish());
+
+        let mut distance: i64 = 0;
+        for (a, b) in left.merge()?.zip(right.merge()?) {
+            distance = a?
+                .checked_sub(b?)
+                .and_then(i64::checked_abs)
+                .and_then(|d| distance.checked_add(d))
+                .ok_or(Error::Overflow)?;
+        }
+
+        // Both lists are sorted, so every occurrence of an id on the right is counted while
+        // walking past it once
+        let mut similarity: i64 = 0;
+        let mut right = right.merge()?;
+        let mut occurrences: Option<(i64, i64)> = None;
+        for a in left.merge()? {
+            let a = a?;
+            if occurrences.is_none_or(|(id, _)| id != a) {
+                let mut count = 0;
+                while let Some(b) = right.peek()
+                    && b <= a
+                {
+                    right.next().transpose()?;
+                    count += (b == a) as i64;
+                }
+                occurrences = Some((a, count));
+            }
+
+            let count = occurrences.map_or(0, |(_, count)| count);
+            similarity = a
+                .checked_mul(count)
+                .and_then(|s| similarity.checked_add(s))
+                .ok_or(Error::Overflow)?;
+        }
+
+        Ok(Totals {
+            distance,
+            similarity,
+        })
+    }
+
+    fn spill_file(&self) -> io::Result<File> {
+        match &self.temp_dir {
+            Some(dir) => tempfile::tempfile_in(dir),
+            None => tempfile::tempfile(),
+        }
+    }
+}
+
+/// One list as it is read, with the runs spilled so far grouped by how many times they
+/// have been merged.
+struct Column<'a> {
+    config: &'a ExternalSort,
+    buffer: Vec<i64>,
+    levels: Vec<Vec<File>>,
+}
+
+impl<'a> Column<'a> {
+    fn new(config: &'a ExternalSort) -> Self {
+        Self {
+            config,
+            buffer: Vec::new(),
+            levels: Vec::new(),
+        }
+    }
+
+    fn push(&mut self, id: i64) -> io::Result<()> {
+        self.buffer.push(id);
+        if self.buffer.len() >= self.config.run_len.max(1) {
+            self.spill()?;
+        }
+        Ok(())
+    }
+
+    fn spill(&mut self) -> io::Result<()> {
+        self.buffer.sort_unstable();
+        let mut run = self.write_run(self.buffer.iter().map(|id| Ok(*id)))?;
+        self.buffer.clear();
+
+        let mut level = 0;
+        loop {
+            if self.levels.len() == level {
+                self.levels.push(Vec::new());
+            }
+            self.levels[level].push(run);
+            if self.levels[level].len() < self.config.fan_in.max(2) {
+                return Ok(());
+            }
+
+            let runs = std::mem::take(&mut self.levels[level]);
+            run = self.write_run(Merge::new(runs.iter().map(Source::file).collect())?)?;
+            level += 1;
+        }
+    }
+
+    fn write_run(&self, ids: impl Iterator<Item = io::Result<i64>>) -> io::Result<File> {
+        let file = self.config.spill_file()?;
+        let mut writer = BufWriter::new(&file);
+        for id in ids {
+            writer.write_all(&id?.to_le_bytes())?;
+        }
+        writer.flush()?;
+        drop(writer);
+
+        Ok(file)
+    }
+
+    /// Sorts what is left in memory, which becomes the last run.
+    fn finish(mut self) -> Sorted {
+        self.buffer.sort_unstable();
+        Sorted {
+            runs: self.levels.into_iter().flatten().collect(),
+            tail: self.buffer,
+        }
+    }
+}
+
+/// A list split into sorted runs, which can be merged as many times as needed.
+struct Sorted {
+    runs: Vec<File>,
+    tail: Vec<i64>,
+}
+
+impl Sorted {
+    fn merge(&self) -> io::Result<Merge<'_>> {
+        let mut sources: Vec<_> = self.runs.iter().map(Source::file).collect();
+        sources.push(Source::Memory(self.tail.iter()));
+        Merge::new(sources)
+    }
+}
+
+enum Source<'a> {
+    File(BufReader<&'a File>),
+    Memory(std::slice::Iter<'a, i64>),
+}
+
+impl<'a> Source<'a> {
+    fn file(file: &'a File) -> Self {
+        Source::File(BufReader::new(file))
+    }
+
+    fn next(&mut self) -> io::Result<Option<i64>> {
+        match self {
+            Source::File(reader) => {
+                let mut bytes = [0; 8];
+                match reader.read_exact(&mut bytes) {
+                    Ok(()) => Ok(Some(i64::from_le_bytes(bytes))),
+                    Err(err) if err.kind() == io::ErrorKind::UnexpectedEof => Ok(None),
+                    Err(err) => Err(err),
+                }
+            }
+            Source::Memory(ids) => Ok(ids.next().copied()),
+        }
+    }
+}
+
+/// The k-way merge of sorted runs, smallest first.
+struct Merge<'a> {
+    sources: Vec<Source<'a>>,
+    heap: BinaryHeap<Reverse<(i64, usize)>>,
+}
+
+impl<'a> Merge<'a> {
+    fn new(mut sources: Vec<Source<'a>>) -> io::Result<Self> {
+        let mut heap = BinaryHeap::with_capacity(sources.len());
+        for (i, source) in sources.iter_mut().enumerate() {
+            if let Source::File(reader) = source {
+                reader.rewind()?;
+            }
+            if let Some(id) = source.next()? {
+                heap.push(Reverse((id, i)));
+            }
+        }
+
+        Ok(Self { sources, heap })
+    }
+
+    fn peek(&self) -> Option<i64> {
+        self.heap.peek().map(|Reverse((id, _))| *id)
+    }
+}
+
+impl Iterator for Merge<'_> {
+    type Item = io::Result<i64>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        let Reverse((id, i)) = self.heap.pop()?;
+        match self.sources[i].next() {
+            Ok(Some(next)) => self.heap.push(Reverse((next, i))),
+            Ok(None) => {}
+            Err(err) => return Some(Err(err)),
+        }
+
+        Some(Ok(id))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::Day1;
+    use aoc_common::solution::{generate, solve};
+
+    fn totals(input: &str, run_len: usize, fan_in: usize) -> Result<Totals, Error> {
+        let config = ExternalSort {
+            run_len,
+            fan_in,
+            temp_dir: None,
+        };
+        config.totals(input.as_bytes())
+    }
+
+    #[test]
+    fn matches_the_in_memory_answers() {
+        for seed in 0..5 {
+            let input = generate::<Day1>(seed, 0.2).unwrap().input;
+            let expected = Totals {
+                distance: solve::<Day1>(&input, 1).unwrap().unwrap().parse().unwrap(),
+                similarity: solve::<Day1>(&input, 2).unwrap().unwrap().parse().unwrap(),
+            };
+
+            // Everything in memory, a few runs, and enough runs to be merged in stages
+            for (run_len, fan_in) in [(usize::MAX, 64), (50, 64), (3, 2)] {
+                assert_eq!(totals(&input, run_len, fan_in).unwrap(), expected);
+            }
+        }
+    }
+
+    #[test]
+    fn allows_blank_lines_only_at_the_end() {
+        let totals_of = |input| totals(input, 2, 2);
+
+        let expected = Totals {
+            distance: 1,
+            similarity: 7,
+        };
+        assert_eq!(totals_of("1 3\n3 1\n4 3\n\n  \n").unwrap(), expected);
+
+        let err = totals_of("1 3\n\n4 3\n").unwrap_err();
+        assert!(matches!(&err, Error::Parse(err) if err.line == 2), "{err}");
+    }
+}
@@ -0,0 +1,48 @@
+use aoc_common::generate::{Generated, Rng, scaled};
+use std::collections::HashMap;
+
+/// Two columns of five digit location ids. Some ids are repeated so that the similarity
+/// score isn't trivially zero.
+pub fn generate(rng: &mut Rng, scale: f64) -> Generated {
+    let lines = scaled(1000, scale);
+
+    let mut left = Vec::with_capacity(lines);
+    let mut right = Vec::with_capacity(lines);
+    for _ in 0..lines {
+        left.push(rng.i64(10_000..100_000));
+        right.push(match right.is_empty() || rng.bool() {
+            true => rng.i64(10_000..100_000),
+            false => left[rng.usize(..left.len())],
+        });
+    }
+
+    let input: String = left
+        .iter()
+        .zip(&right)
+        .map(|(a, b)| format!("{a}   {b}\n"))
+        .collect();
+
+    // Both answers follow directly from their definitions
+    let mut sorted_left = left.clone();
+    let mut sorted_right = right.clone();
+    sorted_left.sort_unstable();
+    sorted_right.sort_unstable();
+    let distance: i64 = sorted_left
+        .iter()
+        .zip(&sorted_right)
+        .map(|(a, b)| (a - b).abs())
+        .sum();
+
+    let mut counts: HashMap<i64, i64> = HashMap::new();
+    for id in &right {
+        *counts.entry(*id).or_default() += 1;
+    }
+    let similarity: i64 = left
+        .iter()
+        .map(|id| id * counts.get(id).unwrap_or(&0))
+        .sum();
+
+    Generated::new(input)
+        .with_answer(1, distance)
+        .with_answer(2, similarity)
+}
@@ -0,0 +1,471 @@
+//! Keeping both totals up to date as ids come and go, without re-sorting the lists.
+//!
+//! The distance between two sorted lists of the same length is also the area between their
+//! counting functions: summing `|cl(x) - cr(x)|` over every `x`, where `cl(x)` and `cr(x)`
+//! count the ids up to `x` in either list. Adding an id to one list and one to the other
+//! only changes that difference between the two ids, so only that stretch is walked again.
+//!
+//! Walking it costs a step for every distinct id between the two, on top of the `O(log n)`
+//! treap lookups, so a pair far apart still walks most of the ids. What each stretch adds
+//! depends on the sign of the difference there, which a sum kept per subtree can't tell.
+
+use std::io::{self, BufRead, Write};
+
+/// Both location lists, with their totals kept up to date.
+///
+/// Ids are added and removed a pair at a time, one in each list, so the lists always have
+/// the same length.
+#[derive(Clone, Debug, Default)]
+pub struct Engine {
+    left: Multiset,
+    right: Multiset,
+    distance: i128,
+    similarity: i128,
+}
+
+impl Engine {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Adds `left` to the left list and `right` to the right one.
+    pub fn insert(&mut self, left: i64, right: i64) {
+        self.distance += self.distance_change(left, right, 1);
+
+        self.similarity += left as i128 * self.right.count(left) as i128;
+        self.left.insert(left);
+        self.similarity += right as i128 * self.left.count(right) as i128;
+        self.right.insert(right);
+    }
+
+    /// Removes one `left` from the left list and one `right` from the right one, returning
+    /// whether both were there. Nothing is removed unless both are.
+    pub fn remove(&mut self, left: i64, right: i64) -> bool {
+        if self.left.count(left) == 0 || self.right.count(right) == 0 {
+            return false;
+        }
+
+        self.distance += self.distance_change(left, right, -1);
+
+        self.similarity -= left as i128 * self.right.count(left) as i128;
+        self.left.remove(left);
+        self.similarity -= right as i128 * self.left.count(right) as i128;
+        self.right.remove(right);
+
+        true
+    }
+
+    pub fn distance(&self) -> i128 {
+        self.distance
+    }
+
+    pub fn similarity(&self) -> i128 {
+        self.similarity
+    }
+
+    /// The length of either list.
+    pub fn len(&self) -> usize {
+        self.left.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.len() == 0
+    }
+
+    // Adding (or removing, with `sign` -1) the pair moves the difference of the counting
+    // functions by one between `left` and `right`, and leaves it alone everywhere else
+    fn distance_change(&self, left: i64, right: i64, sign: i32) -> i128 {
+        let (low, high) = (left.min(right), left.max(right));
+        if low == high {
+            return 0;
+        }
+        let shift = if left < right { sign } else { -sign } as i128;
+
+        let mut difference =
+            self.left.count_up_to(low) as i128 - self.right.count_up_to(low) as i128;
+        let mut steps = self.left.values_between(low, high);
+        for (id, count) in self.right.values_between(low, high) {
+            steps.push((id, -count));
+        }
+        steps.sort_unstable();
+
+        let mut change = 0;
+        let mut from = low;
+        for (id, step) in steps.into_iter().chain([(high, 0)]) {
+            let width = id as i128 - from as i128;
+            change += width * ((difference + shift).abs() - difference.abs());
+            difference += step;
+            from = id;
+        }
+
+        change
+    }
+}
+
+impl Extend<(i64, i64)> for Engine {
+    fn extend<I: IntoIterator<Item = (i64, i64)>>(&mut self, pairs: I) {
+        for (left, right) in pairs {
+            self.insert(left, right);
+        }
+    }
+}
+
+/// Runs the engine's line protocol, answering every command on `reader` with one line on
+/// `writer`:
+///
+/// - `add <left> <right>` and `remove <left> <right>` change the lists and answer with
+///   `ok <distance> <similarity>`
+/// - `totals` answers with `ok <distance> <similarity>` and `len` with `ok <length>`
+/// - anything else answers with `error <message>`
+///
+/// Blank lines and lines starting with `#` are skipped.
+pub fn serve(engine: &mut Engine, reader: impl BufRead, mut writer: impl Write) -> io::Result<()> {
+    for line in reader.lines() {
+        let line = line?;
+        let words: Vec<&str> = line.split_whitespace().collect();
+
+        let response = match words.as_slice() {
+            [] => continue,
+            [comment, ..] if comment.starts_with('#') => continue,
+            ["add", left, right] => pair(left, right).map(|(left, right)| {
+                engine.insert(left, right);
+                totals(engine)
+            }),
+            ["remove", left, right] => {
+                pair(left, right).and_then(|(left, right)| match engine.remove(left, right) {
+                    true => Ok(totals(engine)),
+                    false => Err(format!("no pair {left} {right} to remove")),
+                })
+            }
+            ["totals"] => Ok(totals(engine)),
+            ["len"] => Ok(engine.len().to_string()),
+            _ => Err(format!("unknown command {:?}", line.trim())),
+        };
+
+        match response {
+            Ok(response) => writeln!(writer, "ok {response}")?,
+            Err(message) => writeln!(writer, "error {message}")?,
+        }
+        writer.flush()?;
+    }
+
+    Ok(())
+}
+
+fn pair(left: &str, right: &str) -> Result<(i64, i64), String> {
+    let parse = |id: &str| {
+        id.parse::<i64>()
+            .map_err(|err| format!("{err} (found {id:?})"))
+    };
+    Ok((parse(left)?, parse(right)?))
+}
+
+fn totals(engine: &Engine) -> String {
+    format!("{} {}", engine.distance(), engine.similarity())
+}
+
+/// A multiset of ids that can also count the ids up to a given one, as a treap whose nodes
+/// hold an id, how many times it was added, and how many ids their subtree holds.
+#[derive(Clone, Debug, Default)]
+struct Multiset {
+    nodes: Vec<Node>,
+    root: Option<usize>,
+    /// Slots of removed nodes, reused before growing `nodes`.
+    free: Vec<usize>,
+    /// State of the generator handing out node priorities.
+    seed: u64,
+}
+
+#[derive(Clone, Debug)]
+struct Node {
+    id: i64,
+    count: usize,
+    size: usize,
+    priority: u64,
+    left: Option<usize>,
+    right: Option<usize>,
+}
+
+impl Multiset {
+    fn len(&self) -> usize {
+        self.size(self.root)
+    }
+
+    fn count(&self, id: i64) -> usize {
+        let mut node = self.root;
+        while let Some(i) = node {
+            let n = &self.nodes[i];
+            node = match id.cmp(&n.id) {
+                std::cmp::Ordering::Less => n.left,
+                std::cmp::Ordering::Greater => n.right,
+                std::cmp::Ordering::Equal => return n.count,
+            };
+        }
+        0
+    }
+
+    /// How many ids are less than or equal to `id`.
+    fn count_up_to(&self, id: i64) -> usize {
+        let mut total = 0;
+        let mut node = self.root;
+        while let Some(i) = node {
+            let n = &self.nodes[i];
+            if id < n.id {
+                node = n.left;
+            } else {
+                total += self.size(n.left) + n.count;
+                node = n.right;
+            }
+        }
+        total
+    }
+
+    /// The distinct ids strictly between `low` and `high`, in order, with their counts.
+    fn values_between(&self, low: i64, high: i64) -> Vec<(i64, i128)> {
+        let mut values = Vec::new();
+        self.collect_between(self.root, low, high, &mut values);
+        values
+    }
+
+    fn collect_between(
+        &self,
+        node: Option<usize>,
+        low: i64,
+        high: i64,
+        out: &mut Vec<(i64, i128)>,
+    ) {
+        let Some(i) = node else {
+            return;
+        };
+        let n = &self.nodes[i];
+        if n.id > low {
+            self.collect_between(n.left, low, high, out);
+        }
+        if low < n.id && n.id < high {
+            out.push((n.id, n.count as i128));
+        }
+        if n.id < high {
+            self.collect_between(n.right, low, high, out);
+        }
+    }
+
+    fn insert(&mut self, id: i64) {
+        let (below, rest) = self.split(self.root, |n| n < id);
+        let (equal, above) = self.split(rest, |n| n <= id);
+
+        let equal = match equal {
+            Some(i) => {
+                self.nodes[i].count += 1;
+                self.update(i);
+                i
+            }
+            None => self.new_node(id),
+        };
+
+        let rest = self.merge(Some(equal), above);
+        self.root = self.merge(below, rest);
+    }
+
+    /// Removes one `id`, which must be there.
+    fn remove(&mut self, id: i64) {
+        let (below, rest) = self.split(self.root, |n| n < id);
+        let (equal, above) = self.split(rest, |n| n <= id);
+
+        let i = equal.expect("removed ids are in the set");
+        self.nodes[i].count -= 1;
+        let equal = match self.nodes[i].count {
+            0 => {
+                self.free.push(i);
+                None
+            }
+            _ => {
+                self.update(i);
+                Some(i)
+            }
+        };
+
+        let rest = self.merge(equal, above);
+        self.root = self.merge(below, rest);
+    }
+
+    fn new_node(&mut self, id: i64) -> usize {
+        // splitmix64, which is plenty random for balancing
+        self.seed = self.seed.wrapping_add(0x9e3779b97f4a7c15);
+        let mut priority = self.seed;
+        priority = (priority ^ (priority >> 30)).wrapping_mul(0xbf58476d1ce4e5b9);
+        priority = (priority ^ (priority >> 27)).wrapping_mul(0x94d049bb133111eb);
+        priority ^= priority >> 31;
+
+        let node = Node {
+            id,
+            count: 1,
+            size: 1,
+            priority,
+            left: None,
+            right: None,
+        };
+        match self.free.pop() {
+            Some(i) => {
+                self.nodes[i] = node;
+                i
+            }
+            None => {
+                self.nodes.push(node);
+                self.nodes.len() - 1
+            }
+        }
+    }
+
+    fn size(&self, node: Option<usize>) -> usize {
+        node.map_or(0, |i| self.nodes[i].size)
+    }
+
+    fn update(&mut self, i: usize) {
+        let n = &self.nodes[i];
+        self.nodes[i].size = n.count + self.size(n.left) + self.size(n.right);
+    }
+
+    /// Splits a subtree into the nodes whose ids satisfy `goes_left` and the rest. The ids
+    /// satisfying it must all be smaller than the others.
+    fn split(
+        &mut self,
+        node: Option<usize>,
+        goes_left: impl Fn(i64) -> bool + Copy,
+    ) -> (Option<usize>, Option<usize>) {
+        let Some(i) = node else {
+            return (None, None);
+        };
+
+        if goes_left(self.nodes[i].id) {
+            let (left, right) = self.split(self.nodes[i].right, goes_left);
+            self.nodes[i].right = left;
+            self.update(i);
+            (Some(i), right)
+        } else {
+            let (left, right) = self.split(self.nodes[i].left, goes_left);
+            self.nodes[i].left = right;
+            self.update(i);
+            (left, Some(i))
+        }
+    }
+
+    /// Joins two subtrees, every id of `left` being smaller than every id of `right`.
+    fn merge(&mut self, left: Option<usize>, right: Option<usize>) -> Option<usize> {
+        let (Some(l), Some(r)) = (left, right) else {
+            return left.or(right);
+        };
+
+        if self.nodes[l].priority > self.nodes[r].priority {
+            self.nodes[l].right = self.merge(self.nodes[l].right, Some(r));
+            self.update(l);
+            Some(l)
+        } else {
+            self.nodes[r].left = self.merge(Some(l), self.nodes[r].left);
+            self.update(r);
+            Some(r)
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{lists_distance, lists_similarity};
+    use aoc_common::generate::Rng;
+
+    fn recomputed(left: &[i64], right: &[i64]) -> (i128, i128) {
+        let (mut left, mut right) = (left.to_vec(), right.to_vec());
+        left.sort();
+        right.sort();
+
+        (
+            lists_distance(&left, &right).unwrap() as i128,
+            lists_similarity(&left, &right).unwrap() as i128,
+        )
+    }
+
+    #[test]
+    fn totals_follow_inserts_and_removes() {
+        let mut rng = Rng::with_seed(7);
+        let mut engine = Engine::new();
+        let (mut left, mut right): (Vec<i64>, Vec<i64>) = (Vec::new(), Vec::new());
+
+        for _ in 0..2000 {
+            // Any id of one list can be removed alongside any id of the other
+            if !left.is_empty() && rng.u8(..3) == 0 {
+                let l = left.swap_remove(rng.usize(..left.len()));
+                let r = right.swap_remove(rng.usize(..right.len()));
+                assert!(engine.remove(l, r));
+            } else {
+                let (l, r) = (rng.i64(-20..20), rng.i64(-20..20));
+                engine.insert(l, r);
+                left.push(l);
+                right.push(r);
+            }
+
+            assert_eq!(
+                (engine.distance(), engine.similarity()),
+                recomputed(&left, &right)
+            );
+            assert_eq!(engine.len(), left.len());
+        }
+    }
+
+    #[test]
+    fn totals_follow_pairs_spanning_every_id() {
+        let mut rng = Rng::with_seed(11);
+        let mut engine = Engine::new();
+        let (mut left, mut right): (Vec<i64>, Vec<i64>) = (Vec::new(), Vec::new());
+
+        for i in 0..500 {
+            // Pairs from opposite ends walk across everything added so far
+            let (l, r) = match i % 2 {
+                0 => (
+                    rng.i64(-1_000_000_000_000..0),
+                    rng.i64(0..1_000_000_000_000),
+                ),
+                _ => (
+                    rng.i64(0..1_000_000_000_000),
+                    rng.i64(-1_000_000_000_000..0),
+                ),
+            };
+            engine.insert(l, r);
+            left.push(l);
+            right.push(r);
+
+            if i % 3 == 0 {
+                let (l, r) = (left.swap_remove(0), right.pop().unwrap());
+                assert!(engine.remove(l, r));
+            }
+
+            assert_eq!(
+                (engine.distance(), engine.similarity()),
+                recomputed(&left, &right)
+            );
+        }
+    }
+
+    #[test]
+    fn refuses_to_remove_missing_ids() {
+        let mut engine = Engine::new();
+        engine.extend([(3, 4), (4, 3)]);
+
+        assert!(!engine.remove(3, 5));
+        assert_eq!(
+            (engine.len(), engine.distance(), engine.similarity()),
+            (2, 0, 7)
+        );
+    }
+
+    #[test]
+    fn speaks_the_line_protocol() {
+        let commands = "add 3 4\n# comment\n\nadd 4 3\ntotals\nremove 9 9\nlen\nadd 1\n";
+        let mut output = Vec::new();
+        serve(&mut Engine::new(), commands.as_bytes(), &mut output).unwrap();
+
+        assert_eq!(
+            String::from_utf8(output).unwrap(),
+            "ok 1 0\nok 0 7\nok 0 7\nerror no pair 9 9 to remove\nok 2\nerror unknown command \"add 1\"\n"
+        );
+    }
+}
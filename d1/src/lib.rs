@@ -0,0 +1,251 @@
+use aoc_common::generate::{Generated, Rng};
+use aoc_common::input::Line;
+use aoc_common::solution::{Implementation, unchecked};
+use aoc_common::{ParseError, Solution, input};
+use std::collections::HashMap;
+use std::hash::Hash;
+
+pub mod explain;
+pub mod external;
+mod generate;
+pub mod incremental;
+pub mod metric;
+
+pub struct Day1;
+
+impl Solution for Day1 {
+    const DAY: u8 = 1;
+
+    /// Both location lists, sorted.
+    type Model = (Vec<i64>, Vec<i64>);
+    /// Either answer is `None` if it overflows i64.
+    type Answer1 = Option<i64>;
+    type Answer2 = Option<i64>;
+
+    fn parse(input: &str) -> Result<Self::Model, ParseError> {
+        let mut numbers = read_data(input)?;
+
+        numbers.0.sort();
+        numbers.1.sort();
+
+        Ok(numbers)
+    }
+
+    fn part_1((list1, list2): &Self::Model) -> Option<i64> {
+        lists_distance(list1, list2)
+    }
+
+    fn part_2((list1, list2): &Self::Model) -> Option<i64> {
+        lists_similarity(list1, list2)
+    }
+
+    fn alternatives() -> Vec<Implementation<Self::Model>> {
+        vec![Implementation::new(
+            "frequency table",
+            2,
+            |(list1, list2)| unchecked(counted_similarity(list1, list2)),
+        )]
+    }
+
+    fn generate(rng: &mut Rng, scale: f64) -> Option<Generated> {
+        Some(generate::generate(rng, scale))
+    }
+}
+
+/// Integer widths location ids can be held in.
+pub trait LocationId: Copy + Ord + Hash + TryFrom<usize> {
+    const ZERO: Self;
+
+    fn checked_add(self, other: Self) -> Option<Self>;
+
+    fn checked_mul(self, other: Self) -> Option<Self>;
+}
+
+macro_rules! location_id {
+    ($($t:ty),*) => {
+        $(impl LocationId for $t {
+            const ZERO: Self = 0;
+
+            fn checked_add(self, other: Self) -> Option<Self> {
+                <$t>::checked_add(self, other)
+            }
+
+            fn checked_mul(self, other: Self) -> Option<Self> {
+                <$t>::checked_mul(self, other)
+            }
+        })*
+    };
+}
+
+location_id!(i32, i64, u64);
+
+/// The sum of every id in `list1` times the number of times it appears in `list2`, or
+/// `None` if that doesn't fit in `T`. Both lists must be sorted.
+pub fn lists_similarity<T: LocationId>(list1: &[T], list2: &[T]) -> Option<T> {
+    let mut similarity = T::ZERO;
+    let mut right = list2.chunk_by(|a, b| a == b).peekable();
+
+    // Walk both lists a run of equal ids at a time
+    for run in list1.chunk_by(|a, b| a == b) {
+        let id = run[0];
+        while right.next_if(|r| r[0] < id).is_some() {}
+        let Some(matching) = right.next_if(|r| r[0] == id) else {
+            continue;
+        };
+
+        let occurrences = T::try_from(run.len() * matching.len()).ok()?;
+        similarity = similarity.checked_add(id.checked_mul(occurrences)?)?;
+    }
+
+    Some(similarity)
+}
+
+/// Like [`lists_similarity`], but counting the ids of `list2` in a map so neither list needs
+/// to be sorted.
+pub fn counted_similarity<T: LocationId>(list1: &[T], list2: &[T]) -> Option<T> {
+    let mut counts: HashMap<T, usize> = HashMap::new();
+    for id in list2 {
+        *counts.entry(*id).or_default() += 1;
+    }
+
+    list1.iter().try_fold(T::ZERO, |similarity, id| {
+        let occurrences = T::try_from(counts.get(id).copied().unwrap_or(0)).ok()?;
+        similarity.checked_add(id.checked_mul(occurrences)?)
+    })
+}
+
+/// The sum of the differences between sorted pairs, or `None` if that doesn't fit in i64.
+fn lists_distance(list1: &[i64], list2: &[i64]) -> Option<i64> {
+    list1.iter().zip(list2).try_fold(0i64, |distance, (a, b)| {
+        distance.checked_add(a.checked_sub(*b)?.checked_abs()?)
+    })
+}
+
+fn read_data(input: &str) -> Result<(Vec<i64>, Vec<i64>), ParseError> {
+    let lines: Vec<(i64, i64)> = input::numbered_lines(input)
+        .map(parse_line)
+        .collect::<Result<_, _>>()?;
+
+    let first_column: Vec<i64> = lines.iter().map(|(a, _)| *a).collect();
+    let second_column: Vec<i64> = lines.iter().map(|(_, b)| *b).collect();
+
+    Ok((first_column, second_column))
+}
+
+/// Reads any number of lists, one per whitespace-separated column, each sorted. Every line
+/// must have as many columns as the first.
+pub fn read_columns(input: &str) -> Result<Vec<Vec<i64>>, ParseError> {
+    let mut columns: Vec<Vec<i64>> = Vec::new();
+
+    for line in input::numbered_lines(input) {
+        let expected = match columns.len() {
+            0 => line.split_whitespace().count().max(1),
+            n => n,
+        };
+        let ids = parse_ids(line, expected)?;
+
+        columns.resize_with(expected, Vec::new);
+        for (column, id) in columns.iter_mut().zip(ids) {
+            column.push(id);
+        }
+    }
+
+    for column in &mut columns {
+        column.sort();
+    }
+    Ok(columns)
+}
+
+/// Compares every list with every other, including itself. `matrix[i][j]` compares list `i`
+/// on the left with list `j` on the right.
+pub fn matrix<T>(lists: &[Vec<i64>], compare: impl Fn(&[i64], &[i64]) -> T) -> Vec<Vec<T>> {
+    lists
+        .iter()
+        .map(|left| lists.iter().map(|right| compare(left, right)).collect())
+        .collect()
+}
+
+fn parse_line(line: Line) -> Result<(i64, i64), ParseError> {
+    let ids = parse_ids(line, 2)?;
+    Ok((ids[0], ids[1]))
+}
+
+fn parse_ids(line: Line, columns: usize) -> Result<Vec<i64>, ParseError> {
+    let tokens: Vec<_> = line.split_whitespace().collect();
+    if tokens.len() != columns {
+        let column = tokens
+            .get(columns)
+            .map_or(line.text.chars().count() + 1, |t| t.column);
+        return Err(line.error(
+            column,
+            line.text,
+            format!("expected {columns} columns, found {}", tokens.len()),
+        ));
+    }
+
+    tokens.iter().map(|token| token.parse()).collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn similarities<T: LocationId + From<u8>>() -> (Option<T>, Option<T>) {
+        let list1 = [1, 2, 3, 3, 3, 4].map(T::from);
+        let list2 = [3, 3, 3, 4, 5, 9].map(T::from);
+
+        (
+            lists_similarity(&list1, &list2),
+            counted_similarity(&list1, &list2),
+        )
+    }
+
+    #[test]
+    fn similarity_works_at_every_width() {
+        assert_eq!(similarities::<i32>(), (Some(31), Some(31)));
+        assert_eq!(similarities::<i64>(), (Some(31), Some(31)));
+        assert_eq!(similarities::<u64>(), (Some(31), Some(31)));
+    }
+
+    #[test]
+    fn reads_any_number_of_columns() {
+        let columns = read_columns("3 1 7\n2 5 7\n1 5 0\n").unwrap();
+        assert_eq!(columns, [[1, 2, 3], [1, 5, 5], [0, 7, 7]]);
+
+        let similarity = matrix(&columns, |a, b| lists_similarity(a, b).unwrap());
+        assert_eq!(similarity, [[6, 1, 0], [1, 21, 0], [0, 0, 28]]);
+
+        let err = read_columns("1 2 3\n4 5\n").unwrap_err();
+        assert_eq!((err.line, err.column), (2, 4));
+        assert_eq!(err.message, "expected 3 columns, found 2");
+
+        // Columns count characters, not bytes
+        let err = read_columns("1 2 3\n4\u{a0}5\n").unwrap_err();
+        assert_eq!((err.line, err.column), (2, 4));
+    }
+
+    #[test]
+    fn answers_that_overflow_are_none() {
+        let model = Day1::parse("9223372036854775807 -9223372036854775808\n").unwrap();
+        assert_eq!(Day1::part_1(&model), None);
+
+        let model = Day1::parse(&"4611686018427387904 4611686018427387904\n".repeat(2)).unwrap();
+        assert_eq!(Day1::part_2(&model), None);
+        assert_eq!(unchecked(Day1::part_2(&model)), "overflow");
+    }
+
+    #[test]
+    fn similarity_overflow_is_caught() {
+        let list1 = [i32::MAX / 2, i32::MAX / 2];
+        let list2 = [i32::MAX / 2, i32::MAX / 2];
+
+        assert_eq!(lists_similarity(&list1, &list2), None);
+        assert_eq!(counted_similarity(&list1, &list2), None);
+
+        let widened = list1.map(i64::from);
+        assert_eq!(
+            lists_similarity(&widened, &widened),
+            Some(4 * (i32::MAX / 2) as i64)
+        );
+    }
+}
@@ -0,0 +1,141 @@
+//! Ways of measuring how far apart the two lists are, beyond the puzzle's own distance.
+//!
+//! Paired metrics compare the lists' ids in sorted order, so the lists must be sorted and
+//! of equal length. The others compare the lists as distributions, so only need sorting.
+
+/// A named way of measuring the distance between two sorted lists.
+pub struct Metric {
+    pub name: &'static str,
+    pub description: &'static str,
+    pub distance: fn(&[i64], &[i64]) -> f64,
+}
+
+pub const METRICS: &[Metric] = &[
+    Metric {
+        name: "l1",
+        description: "sum of the differences between sorted pairs, the puzzle's distance",
+        distance: |list1, list2| pair_differences(list1, list2).sum(),
+    },
+    Metric {
+        name: "l2",
+        description: "euclidean distance between the sorted lists",
+        distance: |list1, list2| {
+            pair_differences(list1, list2)
+                .map(|d| d * d)
+                .sum::<f64>()
+                .sqrt()
+        },
+    },
+    Metric {
+        name: "chebyshev",
+        description: "largest difference between sorted pairs",
+        distance: |list1, list2| pair_differences(list1, list2).fold(0.0, f64::max),
+    },
+    Metric {
+        name: "mean",
+        description: "difference between the lists' means",
+        distance: mean_difference,
+    },
+    Metric {
+        name: "median",
+        description: "difference between the lists' medians",
+        distance: |list1, list2| (median(list1) - median(list2)).abs(),
+    },
+    Metric {
+        name: "wasserstein",
+        description: "earth mover's distance between the lists as distributions",
+        distance: wasserstein,
+    },
+];
+
+pub fn find(name: &str) -> Option<&'static Metric> {
+    METRICS.iter().find(|m| m.name == name)
+}
+
+fn pair_differences<'a>(list1: &'a [i64], list2: &'a [i64]) -> impl Iterator<Item = f64> + 'a {
+    list1.iter().zip(list2).map(|(a, b)| a.abs_diff(*b) as f64)
+}
+
+// Works out sum1 / n - sum2 / m over a common denominator, so it only rounds once
+fn mean_difference(list1: &[i64], list2: &[i64]) -> f64 {
+    let sum = |list: &[i64]| list.iter().map(|id| *id as i128).sum::<i128>();
+    let (n, m) = (list1.len() as i128, list2.len() as i128);
+
+    (sum(list1) * m - sum(list2) * n).abs() as f64 / (n * m) as f64
+}
+
+fn median(list: &[i64]) -> f64 {
+    match list.len() {
+        0 => f64::NAN,
+        n if n % 2 == 1 => list[n / 2] as f64,
+        n => (list[n / 2 - 1] as f64 + list[n / 2] as f64) / 2.0,
+    }
+}
+
+// The area between the lists' quantile functions, which also works for lists of different
+// lengths. Between consecutive steps of either function the difference is constant.
+// Quantiles are counted in 1 / (n * m)ths so that the area only rounds at the end.
+fn wasserstein(list1: &[i64], list2: &[i64]) -> f64 {
+    if list1.is_empty() || list2.is_empty() {
+        return f64::NAN;
+    }
+
+    let (n, m) = (list1.len(), list2.len());
+    let (mut i, mut j) = (0, 0);
+    let mut quantile = 0;
+    let mut area: u128 = 0;
+
+    while i < n && j < m {
+        let (step_1, step_2) = ((i + 1) * m, (j + 1) * n);
+        let next = step_1.min(step_2);
+
+        area += (next - quantile) as u128 * list1[i].abs_diff(list2[j]) as u128;
+        quantile = next;
+
+        if step_1 <= step_2 {
+            i += 1;
+        }
+        if step_2 <= step_1 {
+            j += 1;
+        }
+    }
+
+    area as f64 / (n * m) as f64
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn distance(name: &str, list1: &[i64], list2: &[i64]) -> f64 {
+        (find(name).unwrap().distance)(list1, list2)
+    }
+
+    #[test]
+    fn paired_metrics_compare_sorted_pairs() {
+        let (list1, list2) = ([1, 2, 3, 3, 3, 4], [3, 3, 3, 4, 5, 9]);
+
+        assert_eq!(distance("l1", &list1, &list2), 11.0);
+        assert_eq!(distance("l2", &list1, &list2), 35f64.sqrt());
+        assert_eq!(distance("chebyshev", &list1, &list2), 5.0);
+    }
+
+    #[test]
+    fn distribution_metrics_allow_different_lengths() {
+        let (list1, list2) = ([1, 2, 3, 10], [2, 4, 6]);
+
+        assert_eq!(distance("mean", &list1, &list2), 0.0);
+        assert_eq!(distance("median", &list1, &list2), 1.5);
+
+        // Quantiles 0-1/4: 1 vs 2, 1/4-1/3: 2 vs 2, 1/3-1/2: 2 vs 4, 1/2-2/3: 3 vs 4,
+        // 2/3-3/4: 3 vs 6, 3/4-1: 10 vs 6
+        let expected = 1.0 / 4.0 + 2.0 / 6.0 + 1.0 / 6.0 + 3.0 / 12.0 + 4.0 / 4.0;
+        assert_eq!(distance("wasserstein", &list1, &list2), expected);
+    }
+
+    #[test]
+    fn wasserstein_of_equal_lengths_is_the_mean_pair_difference() {
+        let (list1, list2) = ([1, 2, 3, 3, 3, 4], [3, 3, 3, 4, 5, 9]);
+        assert_eq!(distance("wasserstein", &list1, &list2), 11.0 / 6.0);
+    }
+}
@@ -0,0 +1,17 @@
+use aoc_common::{differential, fixtures};
+use d1::Day1;
+
+#[test]
+fn answers_match_fixtures() {
+    fixtures::assert_answers::<Day1>(env!("CARGO_MANIFEST_DIR"));
+}
+
+#[test]
+fn generated_inputs_match_their_answers() {
+    fixtures::assert_generated::<Day1>(1.0);
+}
+
+#[test]
+fn implementations_agree() {
+    differential::assert_agree::<Day1>(env!("CARGO_MANIFEST_DIR"), 0..50, 0.2);
+}
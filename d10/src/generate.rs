@@ -0,0 +1,41 @@
+use aoc_common::generate::{Generated, Rng, scaled_side};
+use aoc_common::{Coords, Grid};
+use std::collections::VecDeque;
+
+/// A square map of hills around random peaks, dropping by one per step away from the
+/// nearest peak down to 0, with some cells scrambled. Trails share cells, so the scores
+/// are only known by exploring and there are no answers.
+pub fn generate(rng: &mut Rng, scale: f64) -> Generated {
+    let side = scaled_side(41, scale);
+
+    // Breadth-first from every peak at once finds each cell's distance to the nearest one
+    let mut distance: Grid<Option<u32>> = Grid::new(side, side, None);
+    let mut queue = VecDeque::new();
+    for _ in 0..(side * side / 150).max(1) {
+        let peak = Coords::new(rng.usize(..side), rng.usize(..side));
+        distance[peak] = Some(0);
+        queue.push_back(peak);
+    }
+    while let Some(coords) = queue.pop_front() {
+        let next = distance[coords].map(|d| d + 1);
+        for neighbour in distance.neighbours(coords).collect::<Vec<_>>() {
+            if distance[neighbour].is_none() {
+                distance[neighbour] = next;
+                queue.push_back(neighbour);
+            }
+        }
+    }
+
+    let height = distance.map(|d| 9 - d.unwrap_or(9).min(9));
+
+    let mut input = String::with_capacity((side + 1) * side);
+    for row in height.rows() {
+        for h in row {
+            let h = if rng.f32() < 0.05 { rng.u32(..10) } else { *h };
+            input.push(char::from_digit(h, 10).unwrap());
+        }
+        input.push('\n');
+    }
+
+    Generated::new(input)
+}
@@ -0,0 +1,136 @@
+use aoc_common::generate::{Generated, Rng};
+use aoc_common::render::{Frame, Rgb};
+use aoc_common::{Coords, Grid, ParseError, Solution};
+use std::collections::HashSet;
+
+mod generate;
+
+pub struct Day10;
+
+impl Solution for Day10 {
+    const DAY: u8 = 10;
+
+    /// The topographic map's elevations.
+    type Model = Grid<usize>;
+    type Answer1 = usize;
+    type Answer2 = usize;
+
+    fn parse(input: &str) -> Result<Self::Model, ParseError> {
+        Grid::parse(input, |c| c.to_digit(10).map(|d| d as usize))
+    }
+
+    fn part_1(data: &Self::Model) -> usize {
+        sum_trailhead_scores(data)
+    }
+
+    fn part_2(data: &Self::Model) -> usize {
+        sum_trailhead_ratings(data)
+    }
+
+    fn visualize(data: &Self::Model, part: u8) -> Option<Frame> {
+        Some(visualize_trails(data, part))
+    }
+
+    fn generate(rng: &mut Rng, scale: f64) -> Option<Generated> {
+        Some(generate::generate(rng, scale))
+    }
+}
+
+fn sum_trailhead_scores(data: &Grid<usize>) -> usize {
+    let mut sum = 0;
+
+    for (coords, elevation) in data.iter() {
+        if *elevation == 0 {
+            let res = explore_next_step(data, coords);
+            let mut peak_coords: HashSet<Coords> = HashSet::new();
+            peak_coords.extend(res);
+            sum += peak_coords.len();
+        }
+    }
+
+    sum
+}
+
+fn sum_trailhead_ratings(data: &Grid<usize>) -> usize {
+    let mut sum = 0;
+
+    for (coords, elevation) in data.iter() {
+        if *elevation == 0 {
+            let res = explore_next_step(data, coords);
+            sum += res.len();
+        }
+    }
+
+    sum
+}
+
+// Returns the coordinates of all valid 9 coordinates reachable from the current position.
+// If no valid coordinates are reachable, returns an empty vector.
+fn explore_next_step(data: &Grid<usize>, coords: Coords) -> Vec<Coords> {
+    let current_elevation: usize = data[coords];
+    if current_elevation == 9 {
+        return vec![coords];
+    }
+
+    let mut results: Vec<Coords> = Vec::new();
+
+    // North, east, south and west
+    for next in data.neighbours(coords) {
+        if data[next] == current_elevation + 1 {
+            results.extend(explore_next_step(data, next));
+        }
+    }
+
+    results
+}
+
+// Returns every hiking trail starting at `coords`, as the cells from there to a 9.
+fn find_trails(data: &Grid<usize>, coords: Coords) -> Vec<Vec<Coords>> {
+    if data[coords] == 9 {
+        return vec![vec![coords]];
+    }
+
+    let mut trails = Vec::new();
+    for next in data.neighbours(coords) {
+        if data[next] == data[coords] + 1 {
+            for mut trail in find_trails(data, next) {
+                trail.insert(0, coords);
+                trails.push(trail);
+            }
+        }
+    }
+
+    trails
+}
+
+// Shades the trails by elevation, blanking out cells on none. Part 1 marks the trailheads
+// and the peaks they reach, part 2 draws every distinct trail.
+fn visualize_trails(data: &Grid<usize>, part: u8) -> Frame {
+    let mut frame = Frame::new(data, |_| '.');
+
+    for (coords, elevation) in data.iter() {
+        if *elevation != 0 {
+            continue;
+        }
+
+        let trails = find_trails(data, coords);
+        for coords in trails.iter().flatten() {
+            let elevation = data[*coords];
+            frame.cell_mut(*coords).glyph = char::from_digit(elevation as u32, 10).unwrap();
+            frame.color(*coords, Rgb::GREY.blend(Rgb::WHITE, elevation as f32 / 9.0));
+        }
+
+        if part == 1 {
+            frame.highlight(coords, Rgb::GREEN);
+            for trail in &trails {
+                frame.highlight(*trail.last().unwrap(), Rgb::RED);
+            }
+        } else {
+            for trail in trails {
+                frame.path(trail, Rgb::BLUE);
+            }
+        }
+    }
+
+    frame
+}
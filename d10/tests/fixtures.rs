@@ -0,0 +1,12 @@
+use aoc_common::fixtures;
+use d10::Day10;
+
+#[test]
+fn answers_match_fixtures() {
+    fixtures::assert_answers::<Day10>(env!("CARGO_MANIFEST_DIR"));
+}
+
+#[test]
+fn generated_inputs_match_their_answers() {
+    fixtures::assert_generated::<Day10>(1.0);
+}
@@ -0,0 +1,156 @@
+//! The Problem Dampener, generalised to removing any number of levels.
+//!
+//! Removing levels to make a report safe is keeping the longest run of levels, not
+//! necessarily adjacent, that is safe on its own. That is found going up and going down in
+//! turn by working out, for every level, the longest safe run ending there.
+
+use crate::policy::{Direction, SafetyPolicy, Trend};
+use std::collections::HashMap;
+
+impl SafetyPolicy {
+    /// The fewest levels that have to be removed for the report to be safe.
+    pub fn min_removals(&self, levels: &[i32]) -> usize {
+        levels.len() - self.longest_safe(levels, levels.len())
+    }
+
+    /// Whether the report is safe once at most `max_removals` levels are removed. Takes time
+    /// linear in the report's length for a fixed `max_removals`.
+    pub fn is_safe_dampened(&self, levels: &[i32], max_removals: usize) -> bool {
+        levels.len() - self.longest_safe(levels, max_removals) <= max_removals
+    }
+
+    // The most levels that can be kept, only looking at ways of keeping them that skip at
+    // most `max_gap` levels at a time. That finds the answer whenever it needs no more than
+    // `max_gap` removals, since those can't skip more.
+    fn longest_safe(&self, levels: &[i32], max_gap: usize) -> usize {
+        let mut longest = levels.len().min(1);
+
+        // A flat report is safe whichever way it is meant to go
+        if self.plateaus {
+            let mut counts: HashMap<i32, usize> = HashMap::new();
+            for level in levels {
+                *counts.entry(*level).or_default() += 1;
+            }
+            longest = longest.max(counts.into_values().max().unwrap_or(0));
+        }
+
+        let directions: &[bool] = match self.direction {
+            Direction::Increasing => &[true],
+            Direction::Decreasing => &[false],
+            Direction::Either => &[true, false],
+        };
+        // Going by the first two levels, a run starting with a plateau has to stay flat
+        let flat_start_allowed =
+            self.direction != Direction::Either || self.trend != Trend::FirstPair;
+
+        for &increasing in directions {
+            let mut ending_at = vec![1; levels.len()];
+            for j in 0..levels.len() {
+                for i in j.saturating_sub(max_gap + 1)..j {
+                    let (a, b) = (levels[i], levels[j]);
+                    if !self.is_step_safe(a, b, Some(increasing)) {
+                        continue;
+                    }
+                    if a == b && ending_at[i] == 1 && !flat_start_allowed {
+                        continue;
+                    }
+                    ending_at[j] = ending_at[j].max(ending_at[i] + 1);
+                }
+            }
+            longest = longest.max(ending_at.into_iter().max().unwrap_or(0));
+        }
+
+        longest
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn counts_the_fewest_removals() {
+        let policy = SafetyPolicy::default();
+
+        assert_eq!(policy.min_removals(&[7, 6, 4, 2, 1]), 0);
+        assert_eq!(policy.min_removals(&[1, 3, 2, 4, 5]), 1);
+        assert_eq!(policy.min_removals(&[1, 2, 7, 8, 9]), 2);
+        assert_eq!(policy.min_removals(&[9, 1, 2, 3, 0, 4]), 2);
+        assert_eq!(policy.min_removals(&[]), 0);
+
+        assert!(!policy.is_safe_dampened(&[1, 2, 7, 8, 9], 1));
+        assert!(policy.is_safe_dampened(&[1, 2, 7, 8, 9], 2));
+    }
+
+    #[test]
+    fn keeps_to_the_policy_trend() {
+        let levels = [5, 5, 6, 7];
+        let plateaus = SafetyPolicy {
+            plateaus: true,
+            ..SafetyPolicy::default()
+        };
+        // Keeping both fives would start with a plateau, so one has to go
+        assert_eq!(plateaus.min_removals(&levels), 1);
+
+        let first_change = SafetyPolicy {
+            trend: Trend::FirstChange,
+            ..plateaus
+        };
+        assert_eq!(first_change.min_removals(&levels), 0);
+    }
+
+    #[test]
+    fn agrees_with_removing_every_combination() {
+        let mut rng = aoc_common::generate::Rng::with_seed(3);
+        let policies = [
+            SafetyPolicy::default(),
+            SafetyPolicy {
+                plateaus: true,
+                ..SafetyPolicy::default()
+            },
+            SafetyPolicy {
+                plateaus: true,
+                trend: Trend::Majority,
+                max_step: 2,
+                ..SafetyPolicy::default()
+            },
+            SafetyPolicy {
+                direction: Direction::Decreasing,
+                min_step: 2,
+                max_step: 4,
+                ..SafetyPolicy::default()
+            },
+        ];
+
+        for _ in 0..300 {
+            let levels: Vec<i32> = (0..rng.usize(0..9)).map(|_| rng.i32(0..8)).collect();
+            for policy in &policies {
+                // Every subset of levels to keep, as a bit mask
+                let brute_force = (0..1u32 << levels.len())
+                    .filter(|mask| {
+                        let kept: Vec<i32> = (0..levels.len())
+                            .filter(|i| mask & (1 << i) != 0)
+                            .map(|i| levels[i])
+                            .collect();
+                        policy.is_safe(&kept)
+                    })
+                    .map(|mask| levels.len() - mask.count_ones() as usize)
+                    .min()
+                    .unwrap();
+
+                assert_eq!(
+                    policy.min_removals(&levels),
+                    brute_force,
+                    "{levels:?} {policy:?}"
+                );
+                for k in 0..3 {
+                    assert_eq!(
+                        policy.is_safe_dampened(&levels, k),
+                        brute_force <= k,
+                        "{levels:?} {policy:?} {k}"
+                    );
+                }
+            }
+        }
+    }
+}
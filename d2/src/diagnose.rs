@@ -0,0 +1,98 @@
+//! Why unsafe reports are unsafe, and whether the Problem Dampener can save them.
+
+use crate::Reports;
+use crate::policy::{Reason, SafetyPolicy};
+use serde::Serialize;
+
+/// What is wrong with one unsafe report.
+#[derive(Clone, Debug, Eq, PartialEq, Serialize)]
+pub struct Diagnosis {
+    /// 1-based input line the report is on.
+    pub report: usize,
+    pub reason: Reason,
+    /// 0-based index of the level the first unsafe step starts from.
+    pub index: usize,
+    /// The two levels either side of the unsafe step.
+    pub values: [i32; 2],
+    /// 0-based index of the first level whose removal makes the report safe, if one does.
+    pub rescued_by: Option<usize>,
+}
+
+/// Diagnoses every report that is unsafe under `policy`, in input order.
+pub fn diagnose(read: &Reports, policy: &SafetyPolicy) -> Vec<Diagnosis> {
+    let mut without = Vec::new();
+
+    read.reports
+        .iter()
+        .zip(&read.lines)
+        .filter_map(|(levels, &line)| {
+            let violation = policy.violation(levels)?;
+            let rescued_by = (0..levels.len()).find(|&removed| {
+                without.clear();
+                without.extend_from_slice(&levels[..removed]);
+                without.extend_from_slice(&levels[removed + 1..]);
+                policy.is_safe(&without)
+            });
+
+            Some(Diagnosis {
+                report: line,
+                reason: violation.reason,
+                index: violation.index,
+                values: [levels[violation.index], levels[violation.index + 1]],
+                rescued_by,
+            })
+        })
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{InputMode, read_reports};
+
+    #[test]
+    fn diagnoses_the_example() {
+        let input = include_str!("../fixtures/example.txt");
+        let read = read_reports(input, InputMode::Strict).unwrap();
+        let diagnoses = diagnose(&read, &SafetyPolicy::default());
+
+        let summary: Vec<_> = diagnoses
+            .iter()
+            .map(|d| (d.report, d.reason, d.index, d.values, d.rescued_by))
+            .collect();
+        assert_eq!(
+            summary,
+            [
+                (2, Reason::StepTooLarge, 1, [2, 7], None),
+                (3, Reason::StepTooLarge, 2, [6, 2], None),
+                (4, Reason::DirectionFlip, 1, [3, 2], Some(1)),
+                (5, Reason::Plateau, 2, [4, 4], Some(2)),
+            ]
+        );
+    }
+
+    #[test]
+    fn reasons_follow_the_policy() {
+        let policy = SafetyPolicy {
+            min_step: 2,
+            ..SafetyPolicy::default()
+        };
+        let read = read_reports("1 3 4 6\n", InputMode::Strict).unwrap();
+        let diagnoses = diagnose(&read, &policy);
+
+        assert_eq!(diagnoses[0].reason, Reason::StepTooSmall);
+        assert_eq!(diagnoses[0].values, [3, 4]);
+        assert_eq!(diagnoses[0].rescued_by, Some(1));
+    }
+
+    #[test]
+    fn reports_are_numbered_by_input_line() {
+        let read = read_reports("1 2\n\n3 4 x\n5 8\n1 1\n", InputMode::Lenient).unwrap();
+        let lines: Vec<_> = diagnose(&read, &SafetyPolicy::default())
+            .iter()
+            .map(|d| (d.report, d.reason))
+            .collect();
+
+        assert_eq!(lines, [(5, Reason::Plateau)]);
+    }
+}
@@ -0,0 +1,73 @@
+use aoc_common::generate::{Generated, Rng, scaled};
+
+/// Reports of five to eight levels, built to be safe, safe once a level is removed, or
+/// unsafe either way, so both answers are known.
+pub fn generate(rng: &mut Rng, scale: f64) -> Generated {
+    let reports = scaled(1000, scale);
+
+    let mut input = String::new();
+    let mut safe = 0;
+    let mut dampened = 0;
+
+    for _ in 0..reports {
+        let len = rng.usize(5..=8);
+        let levels = match rng.u8(..3) {
+            0 => {
+                safe += 1;
+                dampened += 1;
+                safe_levels(rng, len)
+            }
+            1 => {
+                dampened += 1;
+                // Repeating a level makes the report unsafe, and removing either copy
+                // makes it safe again
+                let mut levels = safe_levels(rng, len - 1);
+                let i = rng.usize(..levels.len());
+                levels.insert(i, levels[i]);
+                levels
+            }
+            _ => unsafe_levels(rng, len),
+        };
+
+        let levels: Vec<String> = levels.iter().map(i32::to_string).collect();
+        input += &levels.join(" ");
+        input.push('\n');
+    }
+
+    Generated::new(input)
+        .with_answer(1, safe)
+        .with_answer(2, dampened)
+}
+
+fn safe_levels(rng: &mut Rng, len: usize) -> Vec<i32> {
+    let steps: Vec<i32> = (1..len).map(|_| rng.i32(1..=3)).collect();
+    levels_from_steps(rng, &steps)
+}
+
+// Jumps too far between the second and third levels and again between the fourth and
+// fifth. Removing one level can get rid of at most one of the jumps.
+fn unsafe_levels(rng: &mut Rng, len: usize) -> Vec<i32> {
+    let steps: Vec<i32> = (1..len)
+        .map(|i| match i {
+            2 | 4 => rng.i32(4..=9),
+            _ => rng.i32(1..=3),
+        })
+        .collect();
+    levels_from_steps(rng, &steps)
+}
+
+// Levels taking the given steps from a random start, either all upwards or all downwards.
+fn levels_from_steps(rng: &mut Rng, steps: &[i32]) -> Vec<i32> {
+    let sign = if rng.bool() { 1 } else { -1 };
+    let mut level = match sign {
+        1 => rng.i32(1..30),
+        _ => rng.i32(70..99),
+    };
+
+    let mut levels = vec![level];
+    for step in steps {
+        level += sign * step;
+        levels.push(level);
+    }
+    levels
+}
@@ -0,0 +1,182 @@
+use aoc_common::generate::{Generated, Rng};
+use aoc_common::input::{self, Line};
+use aoc_common::solution::Implementation;
+use aoc_common::{ParseError, Solution};
+
+mod dampener;
+pub mod diagnose;
+mod generate;
+pub mod policy;
+pub mod stream;
+
+pub use policy::SafetyPolicy;
+
+pub struct Day2;
+
+impl Solution for Day2 {
+    const DAY: u8 = 2;
+
+    type Model = Vec<Vec<i32>>;
+    type Answer1 = i32;
+    type Answer2 = i32;
+
+    fn parse(input: &str) -> Result<Self::Model, ParseError> {
+        read_reports(input, InputMode::Strict).map(|read| read.reports)
+    }
+
+    fn part_1(reports: &Self::Model) -> i32 {
+        get_num_safe(reports, &SafetyPolicy::default())
+    }
+
+    fn part_2(reports: &Self::Model) -> i32 {
+        num_safe_dropout(reports, &SafetyPolicy::default(), 1)
+    }
+
+    fn alternatives() -> Vec<Implementation<Self::Model>> {
+        vec![Implementation::new("brute force", 2, |reports| {
+            num_safe_dropout_brute_force(reports, &SafetyPolicy::default()).to_string()
+        })]
+    }
+
+    fn generate(rng: &mut Rng, scale: f64) -> Option<Generated> {
+        Some(generate::generate(rng, scale))
+    }
+}
+
+/// Counts the reports that are safe under `policy` once at most `max_removals` levels are
+/// removed.
+pub fn num_safe_dropout(lines: &[Vec<i32>], policy: &SafetyPolicy, max_removals: usize) -> i32 {
+    let num_safe = lines
+        .iter()
+        .filter(|nums| policy.is_safe_dampened(nums, max_removals))
+        .count();
+
+    num_safe.try_into().unwrap()
+}
+
+// Tries removing every level in turn, which only works for removing one.
+fn num_safe_dropout_brute_force(lines: &[Vec<i32>], policy: &SafetyPolicy) -> i32 {
+    let mut num_unsafe: i32 = 0;
+    let num_total: i32 = lines.len().try_into().unwrap();
+
+    for nums in lines {
+        if policy.is_safe(nums) {
+            continue;
+        }
+
+        let mut is_safe = false;
+
+        // dropout one number at a time
+        for i in 0..nums.len() {
+            let mut nums_dropout = nums.clone();
+            nums_dropout.remove(i);
+
+            if policy.is_safe(&nums_dropout) {
+                is_safe = true;
+                break;
+            }
+        }
+
+        if !is_safe {
+            num_unsafe += 1;
+        }
+    }
+
+    num_total - num_unsafe
+}
+
+/// Counts the reports that are safe under `policy`.
+pub fn get_num_safe(lines: &[Vec<i32>], policy: &SafetyPolicy) -> i32 {
+    let mut num_unsafe: i32 = 0;
+    let num_total: i32 = lines.len().try_into().unwrap();
+
+    for nums in lines {
+        if !policy.is_safe(nums) {
+            num_unsafe += 1;
+        }
+    }
+
+    num_total - num_unsafe
+}
+
+/// What to do with lines that aren't reports.
+#[derive(Clone, Copy, Debug, Default, Eq, PartialEq)]
+pub enum InputMode {
+    /// Fail on the first line that isn't a report.
+    #[default]
+    Strict,
+    /// Skip lines that aren't reports, remembering why.
+    Lenient,
+}
+
+/// The reports read from an input, and the lines skipped in lenient mode.
+#[derive(Clone, Debug, Default)]
+pub struct Reports {
+    pub reports: Vec<Vec<i32>>,
+    /// The 1-based input line each report is on.
+    pub lines: Vec<usize>,
+    pub skipped: Vec<ParseError>,
+}
+
+/// Reads one report per line. A blank line isn't a report, but a report of a single level
+/// is, and is always safe since it has no steps to break the rules.
+pub fn read_reports(input: &str, mode: InputMode) -> Result<Reports, ParseError> {
+    let mut read = Reports::default();
+
+    for line in input::numbered_lines(input) {
+        match parse_report(line) {
+            Ok(levels) => {
+                read.reports.push(levels);
+                read.lines.push(line.number);
+            }
+            Err(err) if mode == InputMode::Lenient => read.skipped.push(err),
+            Err(err) => return Err(err),
+        }
+    }
+
+    Ok(read)
+}
+
+fn parse_report(line: Line) -> Result<Vec<i32>, ParseError> {
+    if line.text.trim().is_empty() {
+        return Err(line.error(1, "", "expected at least one level"));
+    }
+
+    line.split_whitespace().map(|s| s.parse::<i32>()).collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn short_reports_are_safe() {
+        let policy = SafetyPolicy::default();
+
+        assert!(policy.is_safe(&[]));
+        assert!(policy.is_safe(&[7]));
+        assert_eq!(policy.min_removals(&[7]), 0);
+        assert_eq!(num_safe_dropout(&[vec![7], vec![1, 9]], &policy, 1), 2);
+    }
+
+    #[test]
+    fn lenient_mode_skips_lines_that_arent_reports() {
+        let input = "1 2 3\n\n4 x 6\n7\n";
+
+        let err = read_reports(input, InputMode::Strict).unwrap_err();
+        assert_eq!(
+            (err.line, err.message.as_str()),
+            (2, "expected at least one level")
+        );
+
+        let read = read_reports(input, InputMode::Lenient).unwrap();
+        assert_eq!(read.reports, [vec![1, 2, 3], vec![7]]);
+        assert_eq!(read.lines, [1, 4]);
+        let skipped: Vec<_> = read
+            .skipped
+            .iter()
+            .map(|err| (err.line, err.column))
+            .collect();
+        assert_eq!(skipped, [(2, 1), (3, 3)]);
+    }
+}
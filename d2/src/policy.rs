@@ -0,0 +1,288 @@
+//! What makes a report safe, for reactors stricter or more lenient than the puzzle's.
+
+use serde::{Deserialize, Serialize};
+use std::fmt::{self, Display, Formatter};
+use std::str::FromStr;
+
+/// The rules a report's levels must follow to be safe. The default is the puzzle's: steps
+/// of 1 to 3, all in the same direction.
+#[derive(Clone, Debug, Deserialize, Eq, PartialEq)]
+#[serde(default, deny_unknown_fields, rename_all = "kebab-case")]
+pub struct SafetyPolicy {
+    /// Smallest change allowed between adjacent levels, other than a plateau.
+    pub min_step: u32,
+    /// Largest change allowed between adjacent levels.
+    pub max_step: u32,
+    pub direction: Direction,
+    /// Whether adjacent levels may be equal.
+    pub plateaus: bool,
+    /// How the direction of a report is worked out when either is allowed.
+    pub trend: Trend,
+}
+
+impl Default for SafetyPolicy {
+    fn default() -> Self {
+        Self {
+            min_step: 1,
+            max_step: 3,
+            direction: Direction::Either,
+            plateaus: false,
+            trend: Trend::FirstPair,
+        }
+    }
+}
+
+/// Which way the levels of a safe report may go.
+#[derive(Clone, Copy, Debug, Default, Deserialize, Eq, PartialEq)]
+#[serde(rename_all = "kebab-case")]
+pub enum Direction {
+    Increasing,
+    Decreasing,
+    /// Either way, as long as the whole report goes the same way.
+    #[default]
+    Either,
+}
+
+/// How to tell which way a report is meant to go.
+#[derive(Clone, Copy, Debug, Default, Deserialize, Eq, PartialEq)]
+#[serde(rename_all = "kebab-case")]
+pub enum Trend {
+    /// The way the first two levels go, so a report starting with a plateau has to stay
+    /// flat.
+    #[default]
+    FirstPair,
+    /// The way the first two different adjacent levels go.
+    FirstChange,
+    /// The way the first and last levels go.
+    Endpoints,
+    /// The way most steps go, or the first change on a tie.
+    Majority,
+}
+
+/// The first step of a report that breaks a policy.
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub struct Violation {
+    pub reason: Reason,
+    /// 0-based index of the level the step starts from.
+    pub index: usize,
+}
+
+/// Why a step between two levels is unsafe.
+#[derive(Clone, Copy, Debug, Eq, PartialEq, Serialize)]
+#[serde(rename_all = "kebab-case")]
+pub enum Reason {
+    /// The step goes the other way from the report, or the policy's direction.
+    DirectionFlip,
+    /// Two adjacent levels are equal.
+    Plateau,
+    StepTooSmall,
+    StepTooLarge,
+}
+
+impl Display for Reason {
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        let name = match self {
+            Reason::DirectionFlip => "direction flip",
+            Reason::Plateau => "plateau",
+            Reason::StepTooSmall => "step too small",
+            Reason::StepTooLarge => "step too large",
+        };
+        write!(f, "{name}")
+    }
+}
+
+/// A policy file or flag that doesn't describe a policy.
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct PolicyError(pub String);
+
+impl Display for PolicyError {
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        write!(f, "{}", self.0)
+    }
+}
+
+impl std::error::Error for PolicyError {}
+
+impl SafetyPolicy {
+    /// Reads a policy from TOML, like `max-step = 4` or `direction = "increasing"`. Missing
+    /// keys keep their default.
+    pub fn from_toml(text: &str) -> Result<Self, PolicyError> {
+        let policy: Self = toml::from_str(text).map_err(|err| PolicyError(err.to_string()))?;
+        policy.validate()
+    }
+
+    /// Checks the policy can be met at all.
+    pub fn validate(self) -> Result<Self, PolicyError> {
+        if self.min_step > self.max_step {
+            return Err(PolicyError(format!(
+                "min-step {} is larger than max-step {}",
+                self.min_step, self.max_step
+            )));
+        }
+        Ok(self)
+    }
+
+    pub fn is_safe(&self, levels: &[i32]) -> bool {
+        self.violation(levels).is_none()
+    }
+
+    /// The first step of the report that breaks the policy, if any.
+    pub fn violation(&self, levels: &[i32]) -> Option<Violation> {
+        let increasing = match self.direction {
+            Direction::Increasing => Some(true),
+            Direction::Decreasing => Some(false),
+            Direction::Either => self.trend.infer(levels),
+        };
+
+        levels.windows(2).enumerate().find_map(|(index, pair)| {
+            let reason = self.step_violation(pair[0], pair[1], increasing)?;
+            Some(Violation { reason, index })
+        })
+    }
+
+    /// Whether going from `a` to `b` is safe in a report going up, down, or staying flat
+    /// when `increasing` is `None`.
+    pub fn is_step_safe(&self, a: i32, b: i32, increasing: Option<bool>) -> bool {
+        self.step_violation(a, b, increasing).is_none()
+    }
+
+    fn step_violation(&self, a: i32, b: i32, increasing: Option<bool>) -> Option<Reason> {
+        if a == b {
+            return (!self.plateaus).then_some(Reason::Plateau);
+        }
+        if increasing != Some(a < b) {
+            return Some(Reason::DirectionFlip);
+        }
+
+        let step = a.abs_diff(b);
+        if step < self.min_step {
+            Some(Reason::StepTooSmall)
+        } else if step > self.max_step {
+            Some(Reason::StepTooLarge)
+        } else {
+            None
+        }
+    }
+}
+
+impl Trend {
+    /// Whether the levels go up or down, or `None` if they don't go either way.
+    pub fn infer(self, levels: &[i32]) -> Option<bool> {
+        let direction = |a: i32, b: i32| (a != b).then_some(a < b);
+
+        match self {
+            Trend::FirstPair => match levels {
+                [a, b, ..] => direction(*a, *b),
+                _ => None,
+            },
+            Trend::FirstChange => levels.windows(2).find_map(|w| direction(w[0], w[1])),
+            Trend::Endpoints => direction(*levels.first()?, *levels.last()?),
+            Trend::Majority => {
+                let (up, down) = levels.windows(2).fold((0, 0), |(up, down), w| {
+                    (up + (w[0] < w[1]) as usize, down + (w[0] > w[1]) as usize)
+                });
+                match up.cmp(&down) {
+                    std::cmp::Ordering::Greater => Some(true),
+                    std::cmp::Ordering::Less => Some(false),
+                    std::cmp::Ordering::Equal => Trend::FirstChange.infer(levels),
+                }
+            }
+        }
+    }
+}
+
+impl Direction {
+    pub const NAMES: &[&str] = &["increasing", "decreasing", "either"];
+}
+
+impl FromStr for Direction {
+    type Err = PolicyError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s {
+            "increasing" => Ok(Direction::Increasing),
+            "decreasing" => Ok(Direction::Decreasing),
+            "either" => Ok(Direction::Either),
+            _ => Err(PolicyError(format!("unknown direction {s:?}"))),
+        }
+    }
+}
+
+impl Trend {
+    pub const NAMES: &[&str] = &["first-pair", "first-change", "endpoints", "majority"];
+}
+
+impl FromStr for Trend {
+    type Err = PolicyError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s {
+            "first-pair" => Ok(Trend::FirstPair),
+            "first-change" => Ok(Trend::FirstChange),
+            "endpoints" => Ok(Trend::Endpoints),
+            "majority" => Ok(Trend::Majority),
+            _ => Err(PolicyError(format!("unknown trend {s:?}"))),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn reads_policies_from_toml() {
+        let policy = SafetyPolicy::from_toml(
+            "max-step = 5\ndirection = \"increasing\"\nplateaus = true\ntrend = \"majority\"\n",
+        )
+        .unwrap();
+        assert_eq!(
+            policy,
+            SafetyPolicy {
+                min_step: 1,
+                max_step: 5,
+                direction: Direction::Increasing,
+                plateaus: true,
+                trend: Trend::Majority,
+            }
+        );
+
+        assert_eq!(
+            SafetyPolicy::from_toml("").unwrap(),
+            SafetyPolicy::default()
+        );
+        assert!(SafetyPolicy::from_toml("max-steps = 5").is_err());
+        assert!(SafetyPolicy::from_toml("min-step = 4").is_err());
+    }
+
+    #[test]
+    fn plateaus_and_trends() {
+        let flat_start = [5, 5, 6, 8, 9];
+        let puzzle = SafetyPolicy::default();
+        assert!(!puzzle.is_safe(&flat_start));
+
+        let plateaus = SafetyPolicy {
+            plateaus: true,
+            ..SafetyPolicy::default()
+        };
+        assert!(!plateaus.is_safe(&flat_start));
+
+        let first_change = SafetyPolicy {
+            trend: Trend::FirstChange,
+            ..plateaus.clone()
+        };
+        assert!(first_change.is_safe(&flat_start));
+        assert!(!first_change.is_safe(&[5, 5, 4, 6]));
+    }
+
+    #[test]
+    fn trends_disagree_on_zigzags() {
+        let levels = [5, 4, 6, 8, 9];
+
+        assert_eq!(Trend::FirstPair.infer(&levels), Some(false));
+        assert_eq!(Trend::FirstChange.infer(&levels), Some(false));
+        assert_eq!(Trend::Endpoints.infer(&levels), Some(true));
+        assert_eq!(Trend::Majority.infer(&levels), Some(true));
+        assert_eq!(Trend::Majority.infer(&[1, 2, 1]), Some(true));
+    }
+}
@@ -0,0 +1,222 @@
+//! Checking reports one line at a time as they arrive, for logs too long to hold or that
+//! haven't finished being written.
+
+use crate::{InputMode, SafetyPolicy, parse_report};
+use aoc_common::ParseError;
+use aoc_common::input::Line;
+use std::error;
+use std::fmt::{self, Display, Formatter};
+use std::io::{self, BufRead, BufReader, Read};
+
+/// How safe one report is.
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub enum Safety {
+    Safe,
+    /// Only safe once the Problem Dampener removes some levels.
+    Dampened,
+    Unsafe,
+}
+
+impl Display for Safety {
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        let name = match self {
+            Safety::Safe => "safe",
+            Safety::Dampened => "dampened",
+            Safety::Unsafe => "unsafe",
+        };
+        write!(f, "{name}")
+    }
+}
+
+/// What became of one line of the stream.
+#[derive(Clone, Debug)]
+pub enum Outcome {
+    Report {
+        line: usize,
+        safety: Safety,
+    },
+    /// A line that isn't a report, skipped in lenient mode.
+    Skipped(ParseError),
+}
+
+/// Counts of every report seen so far.
+#[derive(Clone, Copy, Debug, Default, Eq, PartialEq)]
+pub struct Tally {
+    pub reports: usize,
+    pub safe: usize,
+    /// Reports that are safe, or safe once the Problem Dampener removes some levels.
+    pub dampened: usize,
+    pub skipped: usize,
+}
+
+#[derive(Debug)]
+pub enum Error {
+    /// Reading the reports failed.
+    Io(io::Error),
+    Parse(ParseError),
+    /// Passing an outcome on failed.
+    Output(io::Error),
+}
+
+impl Display for Error {
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        match self {
+            Error::Io(err) | Error::Output(err) => write!(f, "{err}"),
+            Error::Parse(err) => write!(f, "{err}"),
+        }
+    }
+}
+
+impl error::Error for Error {
+    fn source(&self) -> Option<&(dyn error::Error + 'static)> {
+        match self {
+            Error::Io(err) | Error::Output(err) => Some(err),
+            Error::Parse(err) => Some(err),
+        }
+    }
+}
+
+/// Reads reports from `reader` a line at a time, checking each against `policy` with and
+/// without removing up to `max_removals` levels, and passing what became of it to
+/// `on_outcome` straight away. Lines are read the way [`crate::read_reports`] reads them,
+/// so blank lines at the end are ignored and only fail once a report follows them.
+pub fn check_reports(
+    reader: impl Read,
+    policy: &SafetyPolicy,
+    max_removals: usize,
+    mode: InputMode,
+    mut on_outcome: impl FnMut(&Outcome) -> io::Result<()>,
+) -> Result<Tally, Error> {
+    let mut reader = BufReader::new(reader);
+    let mut text = String::new();
+    let mut number = 0;
+    let mut blank_lines = Vec::new();
+    let mut tally = Tally::default();
+
+    loop {
+        text.clear();
+        if reader.read_line(&mut text).map_err(Error::Io)? == 0 {
+            return Ok(tally);
+        }
+        number += 1;
+
+        let line = Line {
+            number,
+            text: text.trim_end_matches(['\n', '\r']),
+        };
+        if line.text.trim().is_empty() {
+            blank_lines.push(number);
+            continue;
+        }
+
+        // The blank lines weren't at the end after all
+        let pending = blank_lines
+            .drain(..)
+            .map(|number| Line { number, text: "" });
+        for line in pending.chain([line]) {
+            let outcome = match parse_report(line) {
+                Ok(levels) => {
+                    let safety = if policy.is_safe(&levels) {
+                        Safety::Safe
+                    } else if policy.is_safe_dampened(&levels, max_removals) {
+                        Safety::Dampened
+                    } else {
+                        Safety::Unsafe
+                    };
+
+                    tally.reports += 1;
+                    tally.safe += usize::from(safety == Safety::Safe);
+                    tally.dampened += usize::from(safety != Safety::Unsafe);
+                    Outcome::Report {
+                        line: line.number,
+                        safety,
+                    }
+                }
+                Err(err) if mode == InputMode::Lenient => {
+                    tally.skipped += 1;
+                    Outcome::Skipped(err)
+                }
+                Err(err) => return Err(Error::Parse(err)),
+            };
+
+            on_outcome(&outcome).map_err(Error::Output)?;
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{Day2, get_num_safe, num_safe_dropout, read_reports};
+    use aoc_common::Solution;
+
+    fn check(input: &str, mode: InputMode) -> Result<(Tally, Vec<String>), Error> {
+        let mut outcomes = Vec::new();
+        let tally = check_reports(
+            input.as_bytes(),
+            &SafetyPolicy::default(),
+            1,
+            mode,
+            |outcome| {
+                outcomes.push(match outcome {
+                    Outcome::Report { line, safety } => format!("{line} {safety}"),
+                    Outcome::Skipped(err) => format!("{} skipped", err.line),
+                });
+                Ok(())
+            },
+        )?;
+        Ok((tally, outcomes))
+    }
+
+    #[test]
+    fn agrees_with_reading_everything() {
+        let input = include_str!("../fixtures/example.txt");
+        let reports = Day2::parse(input).unwrap();
+        let (tally, outcomes) = check(input, InputMode::Strict).unwrap();
+
+        assert_eq!(tally.reports, reports.len());
+        assert_eq!(tally.safe as i32, Day2::part_1(&reports));
+        assert_eq!(tally.dampened as i32, Day2::part_2(&reports));
+        assert_eq!(outcomes[..2], ["1 safe", "2 unsafe"]);
+        assert_eq!(outcomes[3], "4 dampened");
+    }
+
+    #[test]
+    fn reads_lines_like_read_reports() {
+        let input = "1 2 3\r\n\n4 x 6\n7\n9 1\n\n \n";
+
+        assert!(check(input, InputMode::Strict).is_err());
+
+        let (tally, outcomes) = check(input, InputMode::Lenient).unwrap();
+        let read = read_reports(input, InputMode::Lenient).unwrap();
+        let policy = SafetyPolicy::default();
+        assert_eq!(
+            tally,
+            Tally {
+                reports: read.reports.len(),
+                safe: get_num_safe(&read.reports, &policy) as usize,
+                dampened: num_safe_dropout(&read.reports, &policy, 1) as usize,
+                skipped: read.skipped.len(),
+            }
+        );
+        assert_eq!(
+            outcomes,
+            ["1 safe", "2 skipped", "3 skipped", "4 safe", "5 dampened"]
+        );
+    }
+
+    #[test]
+    fn output_failures_are_told_apart_from_input_ones() {
+        let result = check_reports(
+            "1 2 3\n".as_bytes(),
+            &SafetyPolicy::default(),
+            1,
+            InputMode::Strict,
+            |_| Err(io::Error::from(io::ErrorKind::BrokenPipe)),
+        );
+
+        assert!(
+            matches!(result, Err(Error::Output(err)) if err.kind() == io::ErrorKind::BrokenPipe)
+        );
+    }
+}
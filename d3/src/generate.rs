@@ -0,0 +1,68 @@
+use aoc_common::generate::{Generated, Rng, scaled};
+
+// Filler between instructions, none of which can run into a `mul`, `do` or `don't`.
+const NOISE: &[&str] = &[
+    "where()", "what()", "why()", "who()", "when()", "from()", "how()", "select()", "!", "@", "#",
+    "$", "%", "^", "&", "*", "<", ">", "?", ";", ":", "'", "[", "]", "{", "}", "+", "-", "~", " ",
+    ",", "(", ")",
+];
+
+/// Corrupted memory with valid `mul` instructions, near misses like `mul(4*`, `mul(,4)`,
+/// `mul(1234,5)` or `mul ( 2 , 4 )`, and `do()`/`don't()` toggles, keeping count of both answers as it goes.
+pub fn generate(rng: &mut Rng, scale: f64) -> Generated {
+    let instructions = scaled(700, scale);
+    let lines = scaled(6, scale);
+
+    let mut input = String::new();
+    let mut total: i64 = 0;
+    let mut enabled_total: i64 = 0;
+    let mut enabled = true;
+
+    for i in 0..instructions {
+        for _ in 0..rng.usize(0..8) {
+            input += NOISE[rng.usize(..NOISE.len())];
+        }
+
+        match rng.u8(..10) {
+            0 => {
+                enabled = !enabled;
+                input += if enabled { "do()" } else { "don't()" };
+            }
+            1..=2 => input += &near_miss(rng),
+            _ => {
+                let (a, b) = (rng.i64(1..1000), rng.i64(1..1000));
+                input += &format!("mul({a},{b})");
+                total += a * b;
+                if enabled {
+                    enabled_total += a * b;
+                }
+            }
+        }
+
+        if (i + 1) % instructions.div_ceil(lines) == 0 {
+            input.push('\n');
+        }
+    }
+    if !input.ends_with('\n') {
+        input.push('\n');
+    }
+
+    Generated::new(input)
+        .with_answer(1, total)
+        .with_answer(2, enabled_total)
+}
+
+// Something that looks like a `mul` but isn't one.
+fn near_miss(rng: &mut Rng) -> String {
+    let (a, b) = (rng.u32(1..1000), rng.u32(1..1000));
+    match rng.u8(..8) {
+        0 => format!("mul({a}*"),
+        1 => format!("mul(,{b})"),
+        2 => format!("mul({a},)"),
+        3 => format!("mul[{a},{b}]"),
+        4 => format!("mul ( {a} , {b} )"),
+        5 => format!("mul({a},{b}]"),
+        6 => format!("mul({},{b})", a + 1000),
+        _ => format!("?({a},{b})"),
+    }
+}
@@ -0,0 +1,205 @@
+use aoc_common::generate::{Generated, Rng};
+use aoc_common::solution::Implementation;
+use aoc_common::{ParseError, Solution};
+use regex::Regex;
+
+mod generate;
+
+pub struct Day3;
+
+impl Solution for Day3 {
+    const DAY: u8 = 3;
+
+    /// The corrupted memory, as is.
+    type Model = String;
+    type Answer1 = i64;
+    type Answer2 = i64;
+
+    fn parse(input: &str) -> Result<Self::Model, ParseError> {
+        Ok(input.to_string())
+    }
+
+    fn part_1(memory: &Self::Model) -> i64 {
+        compute_exprs(parse_mul_expr(memory))
+    }
+
+    fn part_2(memory: &Self::Model) -> i64 {
+        compute_flagged(memory)
+    }
+
+    /// The scanner behind part 2 and the regex behind part 1 each solve both parts, so
+    /// they can be checked against each other.
+    fn alternatives() -> Vec<Implementation<Self::Model>> {
+        vec![
+            Implementation::new("scanner", 1, |memory| {
+                compute_scanned(memory, false).to_string()
+            }),
+            Implementation::new("regex", 2, |memory| {
+                compute_exprs(parse_flagged_mul_expr(memory)).to_string()
+            }),
+        ]
+    }
+
+    fn generate(rng: &mut Rng, scale: f64) -> Option<Generated> {
+        Some(generate::generate(rng, scale))
+    }
+}
+
+fn compute_flagged(data: &str) -> i64 {
+    compute_scanned(data, true)
+}
+
+// Scans for `mul` instructions by hand, skipping those after a `don't()` when `honour_flags`
+// is set. Works on bytes, since the instructions are ASCII but the rest of memory needn't be.
+fn compute_scanned(data: &str, honour_flags: bool) -> i64 {
+    let mut sum: i64 = 0;
+    let mut do_op: bool = true;
+
+    let mut curr_mul: String = "".to_string();
+    let mut mul_exprs: Vec<String> = Vec::new();
+
+    let data = data.as_bytes();
+    for i in 4..data.len() {
+        let ch: String = char::from(data[i]).to_string();
+        let mut sub_str = curr_mul.clone();
+
+        if sub_str.is_empty() {
+            // Check the last 4 letters to see if they container the start of a capturing group
+            let window = &data[i - 4..i];
+
+            if window == b"do()" {
+                do_op = true;
+                continue;
+            }
+
+            if i > 6 && &data[i - 7..i] == b"don't()" {
+                do_op = false;
+                continue;
+            }
+
+            if window != b"mul(" {
+                continue;
+            }
+            sub_str = "mul(".to_string();
+        }
+
+        let (new_mul, capturing) = capture_mul(sub_str, ch);
+
+        if new_mul.is_empty() {
+            curr_mul = "".to_string();
+            continue;
+        }
+
+        // Successfully matched a mul expression
+        if !capturing {
+            if do_op || !honour_flags {
+                sum += compute_expr(new_mul.clone());
+            }
+
+            curr_mul = "".to_string();
+            mul_exprs.push(new_mul);
+
+            continue;
+        }
+
+        // Still capturing a mul expression
+        curr_mul = new_mul;
+    }
+
+    sum
+}
+
+fn capture_mul(curr: String, ch: String) -> (String, bool) {
+    if curr.contains(",") {
+        return capture_mul_b(curr, ch);
+    }
+    capture_mul_a(curr, ch)
+}
+
+// Numbers have 1 to 3 digits
+const MAX_DIGITS: usize = 3;
+
+fn capture_mul_a(curr: String, ch: String) -> (String, bool) {
+    // Both numbers need at least one digit, so `mul(,` and `mul(4,)` are not matches
+    if ch == "," && !curr.ends_with('(') {
+        return (curr + &ch, true);
+    }
+    if is_digit(&ch) && trailing_digits(&curr) < MAX_DIGITS {
+        return (curr + &ch, true);
+    }
+    // invalid character
+    ("".to_string(), false)
+}
+
+fn capture_mul_b(curr: String, ch: String) -> (String, bool) {
+    if ch == ")" && !curr.ends_with(',') {
+        return (curr + &ch, false); // done
+    }
+    if is_digit(&ch) && trailing_digits(&curr) < MAX_DIGITS {
+        return (curr + &ch, true);
+    }
+    // invalid character
+    ("".to_string(), false)
+}
+
+fn is_digit(ch: &str) -> bool {
+    ch.len() == 1 && ch.as_bytes()[0].is_ascii_digit()
+}
+
+fn trailing_digits(curr: &str) -> usize {
+    curr.bytes().rev().take_while(u8::is_ascii_digit).count()
+}
+
+fn compute_exprs(exprs: Vec<String>) -> i64 {
+    let mut sum = 0;
+    for expr in exprs {
+        sum += compute_expr(expr);
+    }
+
+    sum
+}
+
+// Both numbers have at most `MAX_DIGITS` digits, so neither they nor their product can
+// overflow.
+fn compute_expr(expr: String) -> i64 {
+    let digits = expr[4..expr.len() - 1]
+        .split(",")
+        .map(|s| s.parse::<i64>().unwrap())
+        .collect::<Vec<i64>>();
+
+    let a = digits[0];
+    let b = digits[1];
+
+    a * b
+}
+
+fn parse_mul_expr(data: &str) -> Vec<String> {
+    let mut exprs: Vec<String> = Vec::new();
+
+    let re = Regex::new(r"mul\(([0-9]{1,3}),([0-9]{1,3})\)").unwrap();
+    let matches = re.find_iter(data);
+    for cap in matches {
+        exprs.push(cap.as_str().to_string());
+    }
+
+    exprs
+}
+
+// Like `parse_mul_expr`, but leaving out the expressions after a `don't()` until the next
+// `do()`.
+fn parse_flagged_mul_expr(data: &str) -> Vec<String> {
+    let mut exprs: Vec<String> = Vec::new();
+    let mut enabled = true;
+
+    let re = Regex::new(r"mul\(([0-9]{1,3}),([0-9]{1,3})\)|do\(\)|don't\(\)").unwrap();
+    for cap in re.find_iter(data) {
+        match cap.as_str() {
+            "do()" => enabled = true,
+            "don't()" => enabled = false,
+            expr if enabled => exprs.push(expr.to_string()),
+            _ => {}
+        }
+    }
+
+    exprs
+}
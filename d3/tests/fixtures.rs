@@ -0,0 +1,17 @@
+use aoc_common::{differential, fixtures};
+use d3::Day3;
+
+#[test]
+fn answers_match_fixtures() {
+    fixtures::assert_answers::<Day3>(env!("CARGO_MANIFEST_DIR"));
+}
+
+#[test]
+fn generated_inputs_match_their_answers() {
+    fixtures::assert_generated::<Day3>(1.0);
+}
+
+#[test]
+fn implementations_agree() {
+    differential::assert_agree::<Day3>(env!("CARGO_MANIFEST_DIR"), 0..50, 0.05);
+}
@@ -0,0 +1,17 @@
+use aoc_common::generate::{Generated, Rng, scaled_side};
+
+/// A square word search of the letters X, M, A and S. How many words it hides is only
+/// known by searching, so there are no answers.
+pub fn generate(rng: &mut Rng, scale: f64) -> Generated {
+    let side = scaled_side(140, scale);
+
+    let mut input = String::with_capacity((side + 1) * side);
+    for _ in 0..side {
+        for _ in 0..side {
+            input.push(['X', 'M', 'A', 'S'][rng.usize(..4)]);
+        }
+        input.push('\n');
+    }
+
+    Generated::new(input)
+}
@@ -0,0 +1,150 @@
+use aoc_common::generate::{Generated, Rng};
+use aoc_common::render::{Frame, Rgb};
+use aoc_common::{Coords, Grid, ParseError, Solution};
+
+mod generate;
+
+pub struct Day4;
+
+impl Solution for Day4 {
+    const DAY: u8 = 4;
+
+    type Model = Grid<char>;
+    type Answer1 = usize;
+    type Answer2 = usize;
+
+    fn parse(input: &str) -> Result<Self::Model, ParseError> {
+        Grid::parse(input, Some)
+    }
+
+    fn part_1(data: &Self::Model) -> usize {
+        count_xmas(data)
+    }
+
+    fn part_2(data: &Self::Model) -> usize {
+        count_x_mas(data)
+    }
+
+    fn visualize(data: &Self::Model, part: u8) -> Option<Frame> {
+        let matches = match part {
+            1 => find_xmas(data),
+            _ => find_x_mas(data),
+        };
+        Some(visualize_matches(data, &matches))
+    }
+
+    fn generate(rng: &mut Rng, scale: f64) -> Option<Generated> {
+        Some(generate::generate(rng, scale))
+    }
+}
+
+fn count_xmas(data: &Grid<char>) -> usize {
+    find_xmas(data).len()
+}
+
+// Returns the cells of every XMAS in the grid.
+fn find_xmas(data: &Grid<char>) -> Vec<Vec<Coords>> {
+    let mut words = Vec::new();
+
+    for coords in data.coords() {
+        // horizontal, vertical, left diagonal and right diagonal. Words running the other
+        // way are matched by checking for the reversed word.
+        for (dx, dy) in [(1, 0), (0, 1), (1, 1), (-1, 1)] {
+            if let Some(s) = word_at(data, coords, (dx, dy), 4)
+                && is_xmas(&s)
+            {
+                words.push(word_cells(coords, (dx, dy), 4));
+            }
+        }
+    }
+
+    words
+}
+
+fn is_xmas(s: &str) -> bool {
+    s == "XMAS" || s == "SAMX"
+}
+
+fn count_x_mas(data: &Grid<char>) -> usize {
+    find_x_mas(data).len()
+}
+
+// Returns the cells of every X-MAS in the grid.
+fn find_x_mas(data: &Grid<char>) -> Vec<Vec<Coords>> {
+    let mut crosses = Vec::new();
+
+    for coords in data.coords() {
+        let mut is_left = false;
+        let mut is_right = false;
+
+        // left diagonal
+        if let Some(s) = word_at(data, coords, (1, 1), 3)
+            && is_mas(&s)
+        {
+            is_left = true;
+        }
+
+        // right diagonal shifted
+        let shifted = coords.offset(2, 0);
+        if let Some(shifted) = shifted
+            && let Some(s) = word_at(data, shifted, (-1, 1), 3)
+            && is_mas(&s)
+        {
+            is_right = true;
+        }
+
+        if is_left
+            && is_right
+            && let Some(shifted) = shifted
+        {
+            let mut cells = word_cells(coords, (1, 1), 3);
+            cells.extend(word_cells(shifted, (-1, 1), 3));
+            crosses.push(cells);
+        }
+    }
+
+    crosses
+}
+
+fn is_mas(s: &str) -> bool {
+    s == "MAS" || s == "SAM"
+}
+
+// Returns the `len` characters starting at `start` and moving by `(dx, dy)` each step, or
+// `None` if the word would run off the grid.
+fn word_at(
+    data: &Grid<char>,
+    start: Coords,
+    (dx, dy): (isize, isize),
+    len: isize,
+) -> Option<String> {
+    (0..len)
+        .map(|i| {
+            start
+                .offset(dx * i, dy * i)
+                .and_then(|c| data.get(c))
+                .copied()
+        })
+        .collect()
+}
+
+// The cells of a word found by `word_at`.
+fn word_cells(start: Coords, (dx, dy): (isize, isize), len: isize) -> Vec<Coords> {
+    (0..len)
+        .filter_map(|i| start.offset(dx * i, dy * i))
+        .collect()
+}
+
+// Draws the letters of each match in colour, blanking out the rest.
+fn visualize_matches(data: &Grid<char>, matches: &[Vec<Coords>]) -> Frame {
+    let mut frame = Frame::new(&Grid::new(data.width(), data.height(), '.'), |c| *c);
+
+    for (i, cells) in matches.iter().enumerate() {
+        for coords in cells {
+            frame.cell_mut(*coords).glyph = data[*coords];
+            frame.color(*coords, Rgb::palette(i));
+        }
+    }
+
+    frame
+}
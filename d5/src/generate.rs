@@ -0,0 +1,53 @@
+use aoc_common::generate::{Generated, Rng, scaled};
+
+/// Rules ordering 49 two digit pages, listing every pair like the real input does, and
+/// updates of an odd number of pages, each either in order or shuffled.
+pub fn generate(rng: &mut Rng, scale: f64) -> Generated {
+    let updates = scaled(190, scale);
+
+    // The order every rule agrees with
+    let mut order: Vec<i32> = (10..59).collect();
+    rng.shuffle(&mut order);
+
+    let mut rules = Vec::new();
+    for (i, a) in order.iter().enumerate() {
+        for b in &order[i + 1..] {
+            rules.push(format!("{a}|{b}"));
+        }
+    }
+    rng.shuffle(&mut rules);
+
+    let mut input = rules.join("\n");
+    input += "\n\n";
+
+    let mut ordered_sum = 0;
+    let mut reordered_sum = 0;
+
+    for _ in 0..updates {
+        let len = 2 * rng.usize(2..12) + 1;
+        let mut positions: Vec<usize> = (0..order.len()).collect();
+        rng.shuffle(&mut positions);
+        positions.truncate(len);
+
+        let mut sorted = positions.clone();
+        sorted.sort_unstable();
+        if rng.bool() {
+            positions = sorted.clone();
+        }
+
+        let middle = order[sorted[len / 2]];
+        if positions == sorted {
+            ordered_sum += middle;
+        } else {
+            reordered_sum += middle;
+        }
+
+        let pages: Vec<String> = positions.iter().map(|p| order[*p].to_string()).collect();
+        input += &pages.join(",");
+        input.push('\n');
+    }
+
+    Generated::new(input)
+        .with_answer(1, ordered_sum)
+        .with_answer(2, reordered_sum)
+}
@@ -0,0 +1,126 @@
+use aoc_common::generate::{Generated, Rng};
+use aoc_common::{ParseError, Solution, input};
+use std::cmp::Ordering;
+use std::collections::{BTreeSet, HashMap};
+
+mod generate;
+
+pub struct Day5;
+
+impl Solution for Day5 {
+    const DAY: u8 = 5;
+
+    /// The page ordering rules and the updates to check against them.
+    type Model = (SortKey, Vec<Vec<i32>>);
+    type Answer1 = i32;
+    type Answer2 = i32;
+
+    fn parse(input: &str) -> Result<Self::Model, ParseError> {
+        read_data(input)
+    }
+
+    fn part_1((sort_key, data): &Self::Model) -> i32 {
+        sum_ordered(sort_key, data)
+    }
+
+    fn part_2((sort_key, data): &Self::Model) -> i32 {
+        sum_reordered(sort_key, data)
+    }
+
+    fn generate(rng: &mut Rng, scale: f64) -> Option<Generated> {
+        Some(generate::generate(rng, scale))
+    }
+}
+
+pub struct SortKey {
+    map: HashMap<BTreeSet<i32>, i32>, // for a pair, value is the number that comes first
+}
+
+fn gen_sort_fn(sort_key: &SortKey) -> impl Fn(&i32, &i32) -> Ordering {
+    |a: &i32, b: &i32| {
+        let key = BTreeSet::from([*a, *b]);
+        let value = sort_key.map.get(&key).unwrap();
+
+        if value == a {
+            Ordering::Less
+        } else {
+            Ordering::Greater
+        }
+    }
+}
+
+fn is_ordered(vec: &[i32], compare: impl Fn(&i32, &i32) -> Ordering) -> bool {
+    for i in 0..vec.len() - 1 {
+        if compare(&vec[i], &vec[i + 1]) == Ordering::Greater {
+            return false;
+        }
+    }
+
+    true
+}
+
+fn sum_reordered(sort_key: &SortKey, data: &[Vec<i32>]) -> i32 {
+    let mut sum = 0;
+    let compare = gen_sort_fn(sort_key);
+
+    for vec in data {
+        if !is_ordered(vec, &compare) {
+            let mut vec = vec.clone();
+            vec.sort_by(&compare);
+
+            let middle = vec[vec.len() / 2];
+            sum += middle;
+        }
+    }
+
+    sum
+}
+
+fn sum_ordered(sort_key: &SortKey, data: &[Vec<i32>]) -> i32 {
+    let mut sum = 0;
+    let compare = gen_sort_fn(sort_key);
+
+    for vec in data {
+        if is_ordered(vec, &compare) {
+            let middle = vec[vec.len() / 2];
+            sum += middle;
+        }
+    }
+
+    sum
+}
+
+fn read_data(input: &str) -> Result<(SortKey, Vec<Vec<i32>>), ParseError> {
+    let mut map: HashMap<BTreeSet<i32>, i32> = HashMap::new();
+
+    let mut data_vec = Vec::new();
+    let mut is_data_mode = false;
+
+    for line in input::numbered_lines(input) {
+        if line.text.is_empty() {
+            is_data_mode = true;
+            continue;
+        }
+
+        if !is_data_mode {
+            let (a, b) = line.split_once('|')?;
+            let a: i32 = a.parse()?;
+            let b: i32 = b.parse()?;
+
+            let mut set: BTreeSet<i32> = BTreeSet::new();
+            set.insert(a);
+            set.insert(b);
+
+            map.insert(set, a);
+            continue;
+        }
+
+        let values: Vec<i32> = line
+            .split(',')
+            .map(|v| v.parse::<i32>())
+            .collect::<Result<_, _>>()?;
+        data_vec.push(values);
+    }
+
+    Ok((SortKey { map }, data_vec))
+}
@@ -0,0 +1,54 @@
+use aoc_common::generate::{Generated, Rng, scaled_side};
+use aoc_common::{Coords, Direction, Grid};
+use std::collections::HashSet;
+
+/// A square lab with scattered obstacles and the guard facing up. Maps where the guard
+/// would patrol forever are thrown away, since the puzzle promises it leaves. The answers
+/// are only known by walking the map, so there are none.
+pub fn generate(rng: &mut Rng, scale: f64) -> Generated {
+    let side = scaled_side(130, scale);
+
+    loop {
+        let mut grid = Grid::new(side, side, false);
+        for coords in grid.coords() {
+            grid[coords] = rng.f32() < 0.05;
+        }
+
+        let start = Coords::new(rng.usize(..side), rng.usize(..side));
+        grid[start] = false;
+
+        if !leaves(&grid, start) {
+            continue;
+        }
+
+        let mut input = String::with_capacity((side + 1) * side);
+        for (y, row) in grid.rows().enumerate() {
+            for (x, obstacle) in row.iter().enumerate() {
+                input.push(match (*obstacle, Coords::new(x, y) == start) {
+                    (_, true) => '^',
+                    (true, _) => '#',
+                    (false, _) => '.',
+                });
+            }
+            input.push('\n');
+        }
+
+        return Generated::new(input);
+    }
+}
+
+// Whether a guard starting at `start` facing up walks off the grid rather than in a loop.
+fn leaves(grid: &Grid<bool>, start: Coords) -> bool {
+    let (mut coords, mut direction) = (start, Direction::Up);
+    let mut seen = HashSet::new();
+
+    while seen.insert((coords, direction)) {
+        match grid.step(coords, direction) {
+            None => return true,
+            Some(next) if grid[next] => direction = direction.turn_right(),
+            Some(next) => coords = next,
+        }
+    }
+
+    false
+}
@@ -0,0 +1,405 @@
+use aoc_common::generate::{Generated, Rng};
+use aoc_common::render::{Frame, Rgb};
+use aoc_common::solution::Implementation;
+use aoc_common::{Coords, Direction, Grid, ParseError, Solution};
+use indexmap::IndexMap;
+use std::collections::{HashMap, HashSet};
+use std::fmt::{self, Debug, Formatter};
+
+mod generate;
+
+/// The guard's position together with the direction it is facing.
+#[derive(Copy, Clone, Hash, Eq, PartialEq)]
+pub struct Guard {
+    coords: Coords,
+    direction: Direction,
+}
+
+impl Debug for Guard {
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        write!(f, "{:?} {:?}", self.coords, self.direction)
+    }
+}
+
+type VisitedMap = Grid<Option<HashMap<Direction, bool>>>;
+
+struct VisitMap(IndexMap<Coords, VisitedDirection>);
+
+impl VisitMap {
+    fn new() -> Self {
+        Self(IndexMap::new())
+    }
+
+    fn visit(&mut self, pos: Coords, direction: Direction) {
+        self.0.entry(pos).or_default().visit(direction);
+    }
+
+    fn contains_key(&self, pos: Coords) -> bool {
+        self.0.contains_key(&pos)
+    }
+}
+
+impl Debug for VisitMap {
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        write!(f, "{:?}", self.0)
+    }
+}
+
+pub struct Day6;
+
+impl Solution for Day6 {
+    const DAY: u8 = 6;
+
+    /// The obstacle map and the guard's starting position.
+    type Model = (Grid<bool>, Guard);
+    type Answer1 = usize;
+    type Answer2 = usize;
+
+    fn parse(input: &str) -> Result<Self::Model, ParseError> {
+        read_map(input)
+    }
+
+    fn part_1((grid, guard): &Self::Model) -> usize {
+        count_visited(grid, *guard)
+    }
+
+    /// Uses the fastest of the part 2 strategies below.
+    fn part_2((grid, guard): &Self::Model) -> usize {
+        part_2_dynamic_rollout(&mut grid.clone(), *guard)
+    }
+
+    fn visualize((grid, guard): &Self::Model, part: u8) -> Option<Frame> {
+        let mut frame = Frame::new(grid, |obstacle| if *obstacle { '#' } else { '.' });
+        frame.path(patrol(grid, *guard), Rgb::BLUE);
+
+        if part == 2 {
+            for coords in loop_obstructions(&mut grid.clone(), *guard) {
+                frame.highlight(coords, Rgb::RED);
+                frame.cell_mut(coords).glyph = 'O';
+            }
+        }
+
+        frame.label(
+            guard.coords,
+            guard.direction.arrow().to_string(),
+            Rgb::GREEN,
+        );
+        Some(frame)
+    }
+
+    fn alternatives() -> Vec<Implementation<Self::Model>> {
+        vec![
+            Implementation::new("skip unreachable", 2, |(grid, guard)| {
+                part_2_skip_unreachable(&mut grid.clone(), *guard).to_string()
+            }),
+            Implementation::new("brute force", 2, |(grid, guard)| {
+                part_2_brute_force(&mut grid.clone(), *guard).to_string()
+            }),
+        ]
+    }
+
+    fn generate(rng: &mut Rng, scale: f64) -> Option<Generated> {
+        Some(generate::generate(rng, scale))
+    }
+}
+
+fn count_visited(grid: &Grid<bool>, guard: Guard) -> usize {
+    let mut guard = guard;
+    let mut visited: VisitedMap = Grid::new(grid.width(), grid.height(), None);
+
+    let mut map = HashMap::new();
+    map.insert(guard.direction, true);
+    visited[guard.coords] = Some(map);
+
+    // A guard that never leaves is counted until it starts going round again
+    let mut states = HashSet::new();
+    while states.insert(guard) {
+        let (next_guard, is_inside, _) = walk(grid, guard, &mut visited);
+        if !is_inside {
+            break;
+        }
+        guard = next_guard;
+    }
+
+    visited.iter().filter(|(_, cell)| cell.is_some()).count()
+}
+
+#[derive(Default)]
+struct VisitedDirection {
+    right: bool,
+    down: bool,
+    left: bool,
+    up: bool,
+}
+
+impl VisitedDirection {
+    fn visit(&mut self, direction: Direction) {
+        match direction {
+            Direction::Right => self.right = true,
+            Direction::Down => self.down = true,
+            Direction::Left => self.left = true,
+            Direction::Up => self.up = true,
+        }
+    }
+}
+
+impl Debug for VisitedDirection {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "[ ")?;
+        if self.up {
+            write!(f, "up ")?;
+        }
+        if self.down {
+            write!(f, "down ")?;
+        }
+        if self.left {
+            write!(f, "left ")?;
+        }
+        if self.right {
+            write!(f, "right ")?;
+        }
+        writeln!(f, "]")
+    }
+}
+
+// Returns the positions the guard walks through until leaving the grid, or going round
+// again, in order.
+fn patrol(grid: &Grid<bool>, start: Guard) -> Vec<Coords> {
+    let mut guard = start;
+    let mut path = vec![guard.coords];
+    let mut states = HashSet::new();
+
+    while states.insert(guard)
+        && let Some(next_guard) = step(grid, &guard)
+    {
+        if is_obstacle(grid, &next_guard) {
+            guard = turn(&guard);
+            continue;
+        }
+
+        path.push(next_guard.coords);
+        guard = next_guard;
+    }
+
+    path
+}
+
+pub fn part_2_dynamic_rollout(grid: &mut Grid<bool>, start: Guard) -> usize {
+    loop_obstructions(grid, start).len()
+}
+
+// For each step, check if putting an obstruction at the next position would result
+// in a loop. Returns the positions where it does.
+fn loop_obstructions(grid: &mut Grid<bool>, start: Guard) -> Vec<Coords> {
+    // A guard that already goes round forever isn't made to loop by any obstruction
+    if walk_until_loop(grid, start) {
+        return Vec::new();
+    }
+
+    let mut obstruction_map: IndexMap<Coords, bool> = IndexMap::new();
+
+    let mut guard = start;
+
+    let mut visited: VisitMap = VisitMap::new();
+
+    loop {
+        visited.visit(guard.coords, guard.direction);
+
+        let Some(next_guard) = step(grid, &guard) else {
+            break;
+        };
+
+        if is_obstacle(grid, &next_guard) {
+            guard = turn(&guard);
+            continue;
+        }
+
+        // Can't put an obstacle at the start position
+        if next_guard.coords == start.coords {
+            guard = next_guard;
+            continue;
+        }
+
+        // If we've already put an obstacle here, skip
+        if obstruction_map.contains_key(&next_guard.coords) {
+            guard = next_guard;
+            continue;
+        }
+
+        // if the obstruction will be on a position we've already visited (but where
+        // it did not result in a loop, and we therefore did not store it), it is
+        // invalid, since we would never have got to where we are.
+        if visited.contains_key(next_guard.coords) {
+            guard = next_guard;
+            continue;
+        }
+
+        // place an obstacle at the next position
+        grid[next_guard.coords] = true;
+
+        // simulate walking from the position before we took a step
+        if walk_until_loop(grid, guard) {
+            obstruction_map.insert(next_guard.coords, true);
+        }
+
+        // remove the obstacle from the next position
+        grid[next_guard.coords] = false;
+
+        guard = next_guard;
+    }
+
+    obstruction_map.into_keys().collect()
+}
+
+// 1. Run first pass and store all visited positions
+// 2. For each possible obstacle position, check if it is visited in the first pass
+// 3. If not, skip it since, we'll never hit it
+pub fn part_2_skip_unreachable(grid: &mut Grid<bool>, start: Guard) -> usize {
+    if walk_until_loop(grid, start) {
+        return 0;
+    }
+
+    let mut obstruction_map: HashMap<Coords, bool> = HashMap::new();
+
+    let mut guard = start;
+
+    let mut visited: HashMap<Coords, bool> = HashMap::new();
+
+    while let Some(next_guard) = step(grid, &guard) {
+        if is_obstacle(grid, &next_guard) {
+            guard = turn(&guard);
+            continue;
+        }
+
+        visited.insert(next_guard.coords, true);
+        guard = next_guard;
+    }
+
+    for coords in grid.coords() {
+        if grid[coords] {
+            continue;
+        }
+
+        if !visited.contains_key(&coords) {
+            continue;
+        }
+
+        grid[coords] = true;
+
+        if walk_until_loop(grid, start) {
+            obstruction_map.insert(coords, true);
+        }
+        grid[coords] = false;
+    }
+
+    obstruction_map.len()
+}
+
+pub fn part_2_brute_force(grid: &mut Grid<bool>, start: Guard) -> usize {
+    if walk_until_loop(grid, start) {
+        return 0;
+    }
+
+    let mut obstruction_map: HashMap<Coords, bool> = HashMap::new();
+
+    for coords in grid.coords() {
+        if grid[coords] {
+            continue;
+        }
+
+        grid[coords] = true;
+
+        if walk_until_loop(grid, start) {
+            obstruction_map.insert(coords, true);
+        }
+        grid[coords] = false;
+    }
+
+    obstruction_map.len()
+}
+
+// Whether the guard goes round forever instead of leaving the grid. Turns are recorded as
+// well as steps, so a guard walled in on every side, which only ever turns, is caught too.
+fn walk_until_loop(grid: &Grid<bool>, start: Guard) -> bool {
+    let mut guard = start;
+    let mut visited: HashSet<Guard> = HashSet::new();
+
+    loop {
+        if !visited.insert(guard) {
+            return true;
+        }
+
+        let Some(next_guard) = step(grid, &guard) else {
+            return false;
+        };
+
+        guard = if is_obstacle(grid, &next_guard) {
+            turn(&guard)
+        } else {
+            next_guard
+        };
+    }
+}
+
+/// Returns the next guard state, whether we're still inside the grid, and whether
+/// we hit an obstacle.
+fn walk(grid: &Grid<bool>, start: Guard, visited: &mut VisitedMap) -> (Guard, bool, bool) {
+    let Some(next_guard) = step(grid, &start) else {
+        return (start, false, false);
+    };
+
+    if is_obstacle(grid, &next_guard) {
+        return (turn(&start), true, true);
+    }
+
+    visited[next_guard.coords]
+        .get_or_insert_with(HashMap::new)
+        .insert(next_guard.direction, true);
+
+    (next_guard, true, false)
+}
+
+fn turn(guard: &Guard) -> Guard {
+    Guard {
+        coords: guard.coords,
+        direction: guard.direction.turn_right(),
+    }
+}
+
+// Returns the guard one step ahead, or `None` if that step leaves the grid.
+fn step(grid: &Grid<bool>, guard: &Guard) -> Option<Guard> {
+    let coords = grid.step(guard.coords, guard.direction)?;
+    Some(Guard {
+        coords,
+        direction: guard.direction,
+    })
+}
+
+fn is_obstacle(grid: &Grid<bool>, guard: &Guard) -> bool {
+    grid[guard.coords]
+}
+
+fn read_map(input: &str) -> Result<(Grid<bool>, Guard), ParseError> {
+    let mut guard = None;
+
+    let grid = Grid::parse(input, |c| match c {
+        '#' => Some(true),
+        '.' | '^' | 'v' | '<' | '>' => Some(false),
+        _ => None,
+    })?;
+
+    for (y, line) in input.lines().enumerate() {
+        for (x, c) in line.chars().enumerate() {
+            if let Some(direction) = Direction::from_arrow(c) {
+                guard = Some(Guard {
+                    coords: Coords::new(x, y),
+                    direction,
+                });
+            }
+        }
+    }
+
+    let guard = guard.ok_or_else(|| ParseError::at_end(input, "no guard on the map"))?;
+
+    Ok((grid, guard))
+}
@@ -0,0 +1,52 @@
+use aoc_common::{Grid, Solution};
+use aoc_common::{differential, fixtures};
+use d6::{Day6, Guard};
+
+type Strategy = fn(&mut Grid<bool>, Guard) -> usize;
+
+#[test]
+fn answers_match_fixtures() {
+    fixtures::assert_answers::<Day6>(env!("CARGO_MANIFEST_DIR"));
+}
+
+fn assert_strategy(strategy: Strategy) {
+    let fixtures = fixtures::load(env!("CARGO_MANIFEST_DIR")).unwrap();
+
+    for fixture in fixtures.iter().filter(|f| f.part == 2) {
+        let input = fixture.read_input().unwrap();
+        let (mut grid, guard) = Day6::parse(&input).unwrap();
+
+        let answer = strategy(&mut grid, guard);
+        assert_eq!(
+            answer.to_string(),
+            fixture.answer,
+            "{}",
+            fixture.input.display()
+        );
+    }
+}
+
+#[test]
+fn part_2_dynamic_rollout() {
+    assert_strategy(d6::part_2_dynamic_rollout);
+}
+
+#[test]
+fn part_2_skip_unreachable() {
+    assert_strategy(d6::part_2_skip_unreachable);
+}
+
+#[test]
+fn part_2_brute_force() {
+    assert_strategy(d6::part_2_brute_force);
+}
+
+#[test]
+fn generated_inputs_match_their_answers() {
+    fixtures::assert_generated::<Day6>(0.1);
+}
+
+#[test]
+fn implementations_agree() {
+    differential::assert_agree::<Day6>(env!("CARGO_MANIFEST_DIR"), 0..50, 0.1);
+}
@@ -0,0 +1,45 @@
+use aoc_common::generate::{Generated, Rng, scaled};
+
+/// Equations whose test value comes from applying random operators to their numbers, so
+/// all of them can be made true. Part 2's answer is therefore their sum. Part 1's isn't
+/// known, as an equation built with `||` may or may not also work with `+` and `*` alone.
+pub fn generate(rng: &mut Rng, scale: f64) -> Generated {
+    let equations = scaled(850, scale);
+
+    let mut input = String::new();
+    // Many large values at big scales can add up to more than an i64
+    let mut total: i128 = 0;
+
+    for _ in 0..equations {
+        // Keep the numbers to 15 digits between them, a little more than the real input,
+        // so that even concatenating all of them fits in an i64
+        let mut numbers: Vec<i64> = Vec::new();
+        let mut digits = 0;
+        for _ in 0..rng.usize(2..=12) {
+            let number = match rng.u8(..3) {
+                0 => rng.i64(1..10),
+                1 => rng.i64(10..100),
+                _ => rng.i64(100..1000),
+            };
+            digits += number.to_string().len();
+            if digits > 15 {
+                break;
+            }
+            numbers.push(number);
+        }
+
+        let value: i64 = numbers[1..]
+            .iter()
+            .fold(numbers[0], |acc, b| match rng.u8(..3) {
+                0 => acc + b,
+                1 => acc * b,
+                _ => format!("{acc}{b}").parse().unwrap(),
+            });
+        total += value as i128;
+
+        let numbers: Vec<String> = numbers.iter().map(i64::to_string).collect();
+        input += &format!("{value}: {}\n", numbers.join(" "));
+    }
+
+    Generated::new(input).with_answer(2, total)
+}
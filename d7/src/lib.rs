@@ -1,21 +1,37 @@
+use aoc_common::generate::{Generated, Rng};
+use aoc_common::{ParseError, Solution, input};
 use rayon::prelude::*;
-use std::fs::File;
-use std::io::Read;
-use std::time::Instant;
 
-fn main() {
-    let data = read_data();
-    let sum_1 = part_1(&data);
+mod generate;
 
-    let start = Instant::now();
-    let sum_2 = part_2(&data);
-    let duration = start.elapsed();
+pub struct Day7;
 
-    println!("Part 1: {:?}", sum_1);
-    println!("Part 2: {:?}, {:?}", sum_2, duration);
+impl Solution for Day7 {
+    const DAY: u8 = 7;
+
+    /// Each equation's test value and its operands.
+    type Model = Vec<(i64, Vec<i64>)>;
+    type Answer1 = i64;
+    type Answer2 = i64;
+
+    fn parse(input: &str) -> Result<Self::Model, ParseError> {
+        read_data(input)
+    }
+
+    fn part_1(data: &Self::Model) -> i64 {
+        sum_solvable(data)
+    }
+
+    fn part_2(data: &Self::Model) -> i64 {
+        sum_solvable_with_concat(data)
+    }
+
+    fn generate(rng: &mut Rng, scale: f64) -> Option<Generated> {
+        Some(generate::generate(rng, scale))
+    }
 }
 
-fn part_1(data: &Vec<(i64, Vec<i64>)>) -> i64 {
+fn sum_solvable(data: &[(i64, Vec<i64>)]) -> i64 {
     let mut sum = 0;
 
     for (res, numbers) in data {
@@ -31,7 +47,7 @@ fn part_1(data: &Vec<(i64, Vec<i64>)>) -> i64 {
     sum
 }
 
-fn part_2(data: &Vec<(i64, Vec<i64>)>) -> i64 {
+fn sum_solvable_with_concat(data: &[(i64, Vec<i64>)]) -> i64 {
     let sum: i64 = data
         .par_iter()
         .map(|(res, numbers)| {
@@ -39,11 +55,7 @@ fn part_2(data: &Vec<(i64, Vec<i64>)>) -> i64 {
             let remaining = &numbers[1..];
             let tree = reduce_part_2(acc, remaining);
 
-            if let Some(leaf) = tree.find_eq(*res) {
-                leaf
-            } else {
-                0
-            }
+            tree.find_eq(*res).unwrap_or(0)
         })
         .sum();
 
@@ -75,15 +87,7 @@ impl Tree {
     }
 
     fn find_eq(&self, target: i64) -> Option<i64> {
-        let leaves = self.get_leaves();
-
-        for leaf in leaves {
-            if leaf == target {
-                return Some(leaf);
-            }
-        }
-
-        None
+        self.get_leaves().into_iter().find(|leaf| *leaf == target)
     }
 }
 
@@ -116,15 +120,7 @@ impl Tree2 {
     }
 
     fn find_eq(&self, target: i64) -> Option<i64> {
-        let leaves = self.get_leaves();
-
-        for leaf in leaves {
-            if leaf == target {
-                return Some(leaf);
-            }
-        }
-
-        None
+        self.get_leaves().into_iter().find(|leaf| *leaf == target)
     }
 }
 
@@ -145,18 +141,16 @@ fn reduce_part_2(acc: i64, vec: &[i64]) -> Tree2 {
     let concat_acc = acc.to_string() + &b.to_string();
     let concat_acc = concat_acc.parse::<i64>().unwrap();
 
-    let mul_tree = reduce_part_2(mul_acc, &remaining);
-    let plus_tree = reduce_part_2(plus_acc, &remaining);
-    let concat_tree = reduce_part_2(concat_acc, &remaining);
+    let mul_tree = reduce_part_2(mul_acc, remaining);
+    let plus_tree = reduce_part_2(plus_acc, remaining);
+    let concat_tree = reduce_part_2(concat_acc, remaining);
 
-    let tree = Tree2 {
+    Tree2 {
         acc,
         mul: Some(Box::new(mul_tree)),
         plus: Some(Box::new(plus_tree)),
         concat: Some(Box::new(concat_tree)),
-    };
-
-    return tree;
+    }
 }
 
 // Returns the result and the sequence of operations
@@ -177,13 +171,11 @@ fn reduce(acc: i64, vec: &[i64]) -> Tree {
     let mul_tree = reduce(mul_acc, remaining);
     let plus_tree = reduce(plus_acc, remaining);
 
-    let tree = Tree {
+    Tree {
         acc,
         mul: Some(Box::new(mul_tree)),
         plus: Some(Box::new(plus_tree)),
-    };
-
-    return tree;
+    }
 }
 
 fn apply_op(op: String, a: i64, b: i64) -> i64 {
@@ -194,26 +186,24 @@ fn apply_op(op: String, a: i64, b: i64) -> i64 {
     }
 }
 
-fn read_data() -> Vec<(i64, Vec<i64>)> {
-    let mut file = File::open("data.txt").unwrap();
-    let mut data = String::new();
-    file.read_to_string(&mut data).unwrap();
-
+fn read_data(input: &str) -> Result<Vec<(i64, Vec<i64>)>, ParseError> {
     let mut result: Vec<(i64, Vec<i64>)> = Vec::new();
 
-    for line in data.lines() {
-        let res = line.split(":").next().unwrap();
-        let numbers = line.split(":").nth(1).unwrap();
+    for line in input::numbered_lines(input) {
+        let (res, numbers) = line.split_once(':')?;
 
-        let a: i64 = res.parse::<i64>().unwrap();
+        let a: i64 = res.parse::<i64>()?;
         let b: Vec<i64> = numbers
-            .split(" ")
-            .filter(|v| !v.is_empty())
-            .map(|v| v.parse::<i64>().unwrap())
-            .collect();
+            .split_whitespace()
+            .map(|v| v.parse::<i64>())
+            .collect::<Result<_, _>>()?;
+
+        if b.is_empty() {
+            return Err(numbers.error("expected at least one number"));
+        }
 
         result.push((a, b));
     }
 
-    result
+    Ok(result)
 }
@@ -0,0 +1,28 @@
+use aoc_common::generate::{Generated, Rng, scaled, scaled_side};
+
+const FREQUENCIES: &[u8] = b"abcdefghijklmnopqrstuvwxyzABCDEFGHIJKLMNOPQRSTUVWXYZ0123456789";
+
+/// A square map with a few antennas on each of several frequencies. The anti-nodes are
+/// only known by finding them, so there are no answers.
+pub fn generate(rng: &mut Rng, scale: f64) -> Generated {
+    let side = scaled_side(50, scale);
+    let antennas = scaled(150, scale).min(side * side);
+
+    let mut map = vec![b'.'; side * side];
+    let mut placed = 0;
+    while placed < antennas {
+        let i = rng.usize(..map.len());
+        if map[i] == b'.' {
+            map[i] = FREQUENCIES[rng.usize(..FREQUENCIES.len())];
+            placed += 1;
+        }
+    }
+
+    let mut input = String::with_capacity((side + 1) * side);
+    for row in map.chunks(side) {
+        input.extend(row.iter().map(|c| *c as char));
+        input.push('\n');
+    }
+
+    Generated::new(input)
+}
@@ -0,0 +1,168 @@
+use aoc_common::generate::{Generated, Rng};
+use aoc_common::render::{Frame, Rgb};
+use aoc_common::{Coords, Grid, ParseError, Solution};
+use std::collections::{HashMap, HashSet};
+
+mod generate;
+
+pub struct Day8;
+
+impl Solution for Day8 {
+    const DAY: u8 = 8;
+
+    type Model = Grid<char>;
+    type Answer1 = usize;
+    type Answer2 = usize;
+
+    fn parse(input: &str) -> Result<Self::Model, ParseError> {
+        Grid::parse(input, Some)
+    }
+
+    fn part_1(data: &Self::Model) -> usize {
+        count_anti_nodes(data)
+    }
+
+    fn part_2(data: &Self::Model) -> usize {
+        count_resonant_anti_nodes(data)
+    }
+
+    fn visualize(data: &Self::Model, part: u8) -> Option<Frame> {
+        let anti_nodes = match part {
+            1 => find_anti_nodes(data),
+            _ => find_resonant_anti_nodes(data),
+        };
+        Some(visualize_anti_nodes(&anti_nodes, data))
+    }
+
+    fn generate(rng: &mut Rng, scale: f64) -> Option<Generated> {
+        Some(generate::generate(rng, scale))
+    }
+}
+
+fn count_anti_nodes(data: &Grid<char>) -> usize {
+    find_anti_nodes(data).len()
+}
+
+fn find_anti_nodes(data: &Grid<char>) -> HashSet<Coords> {
+    let antennas = generate_antennas_map(data);
+    let mut anti_nodes_map: HashSet<Coords> = HashSet::new();
+
+    for (_, coords_vec) in antennas {
+        for i in 0..coords_vec.len() {
+            for j in i + 1..coords_vec.len() {
+                let coord_1 = coords_vec[i];
+                let coord_2 = coords_vec[j];
+
+                let anti_nodes = calc_anti_nodes(coord_1, coord_2, data, Some(1));
+                anti_nodes_map.extend(anti_nodes);
+            }
+        }
+    }
+
+    anti_nodes_map
+}
+
+fn count_resonant_anti_nodes(data: &Grid<char>) -> usize {
+    find_resonant_anti_nodes(data).len()
+}
+
+fn find_resonant_anti_nodes(data: &Grid<char>) -> HashSet<Coords> {
+    let antennas = generate_antennas_map(data);
+
+    let mut anti_nodes_map: HashSet<Coords> = HashSet::new();
+
+    for (_, coords_vec) in antennas {
+        for i in 0..coords_vec.len() {
+            for j in i + 1..coords_vec.len() {
+                let coord_1 = coords_vec[i];
+                let coord_2 = coords_vec[j];
+
+                let anti_nodes = calc_anti_nodes(coord_1, coord_2, data, None);
+                anti_nodes_map.extend(anti_nodes);
+            }
+
+            // The anti-nodes also occur at the antenna position for any pair of antennas
+            if coords_vec.len() > 1 {
+                anti_nodes_map.insert(coords_vec[i]);
+            }
+        }
+    }
+
+    anti_nodes_map
+}
+
+fn generate_antennas_map(data: &Grid<char>) -> HashMap<char, Vec<Coords>> {
+    let mut antennas: HashMap<char, Vec<Coords>> = HashMap::new();
+
+    for (coords, c) in data.iter() {
+        if *c == '.' {
+            continue;
+        }
+
+        antennas.entry(*c).or_default().push(coords);
+    }
+
+    antennas
+}
+
+// Colours each frequency's antennas differently and marks the anti-nodes with `#`.
+fn visualize_anti_nodes(anti_nodes: &HashSet<Coords>, data: &Grid<char>) -> Frame {
+    let mut frame = Frame::new(data, |c| *c);
+
+    let mut frequencies: Vec<char> = data.iter().map(|(_, c)| *c).filter(|c| *c != '.').collect();
+    frequencies.sort_unstable();
+    frequencies.dedup();
+
+    for (coords, c) in data.iter() {
+        if let Ok(i) = frequencies.binary_search(c) {
+            frame.color(coords, Rgb::palette(i));
+        }
+    }
+
+    for coords in anti_nodes {
+        frame.highlight(*coords, Rgb::MAGENTA.blend(Rgb::BLACK, 0.5));
+        if data[*coords] == '.' {
+            frame.cell_mut(*coords).glyph = '#';
+        }
+    }
+
+    frame
+}
+
+// Returns the anti-nodes for a given pair of coordinates.
+// The result could be an empty vector if both the anti-nodes fall outside the map (unlikely)
+fn calc_anti_nodes(
+    coord_1: Coords,
+    coord_2: Coords,
+    data: &Grid<char>,
+    max_anti_nodes: Option<usize>,
+) -> Vec<Coords> {
+    let mut anti_nodes: Vec<Coords> = Vec::new();
+
+    let diff_x = coord_1.x as isize - coord_2.x as isize;
+    let diff_y = coord_1.y as isize - coord_2.y as isize;
+
+    // first anti-node, then second anti-node
+    for (origin, sign) in [(coord_1, 1), (coord_2, -1)] {
+        let mut multiplier: usize = 1;
+        loop {
+            let offset = sign * multiplier as isize;
+            let coord = origin.offset(diff_x * offset, diff_y * offset);
+
+            // Off the map
+            let Some(coord) = coord.filter(|c| data.contains(*c)) else {
+                break;
+            };
+            anti_nodes.push(coord);
+
+            if let Some(max_anti_nodes) = max_anti_nodes
+                && multiplier >= max_anti_nodes
+            {
+                break;
+            }
+            multiplier += 1;
+        }
+    }
+
+    anti_nodes
+}
@@ -0,0 +1,12 @@
+use aoc_common::fixtures;
+use d8::Day8;
+
+#[test]
+fn answers_match_fixtures() {
+    fixtures::assert_answers::<Day8>(env!("CARGO_MANIFEST_DIR"));
+}
+
+#[test]
+fn generated_inputs_match_their_answers() {
+    fixtures::assert_generated::<Day8>(1.0);
+}
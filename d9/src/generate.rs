@@ -0,0 +1,19 @@
+use aoc_common::generate::{Generated, Rng, scaled};
+
+/// A disk map alternating file sizes of 1 to 9 blocks with free spans of 0 to 9, starting
+/// and ending with a file. The checksums are only known by compacting, so there are no
+/// answers.
+pub fn generate(rng: &mut Rng, scale: f64) -> Generated {
+    let files = scaled(10_000, scale);
+
+    let mut input = String::with_capacity(2 * files);
+    for i in 0..files {
+        if i > 0 {
+            input.push(char::from_digit(rng.u32(..10), 10).unwrap());
+        }
+        input.push(char::from_digit(rng.u32(1..10), 10).unwrap());
+    }
+    input.push('\n');
+
+    Generated::new(input)
+}
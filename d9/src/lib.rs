@@ -0,0 +1,202 @@
+use aoc_common::generate::{Generated, Rng};
+use aoc_common::render::{Frame, Rgb};
+use aoc_common::{Coords, Grid, ParseError, Solution, input};
+
+mod generate;
+
+pub struct Day9;
+
+impl Solution for Day9 {
+    const DAY: u8 = 9;
+
+    /// The disk map, one digit per entry.
+    type Model = Vec<usize>;
+    type Answer1 = i64;
+    type Answer2 = i64;
+
+    fn parse(input: &str) -> Result<Self::Model, ParseError> {
+        read_data(input)
+    }
+
+    fn part_1(data: &Self::Model) -> i64 {
+        let disk = compute_disk_layout(data);
+        let compacted_disk = compact_disk(&disk);
+        compute_checksum(&compacted_disk)
+    }
+
+    fn part_2(data: &Self::Model) -> i64 {
+        let disk = compute_disk_layout(data);
+        let compacted_disk = compact_disk_2(&disk, data);
+        compute_checksum(&compacted_disk)
+    }
+
+    fn visualize(data: &Self::Model, part: u8) -> Option<Frame> {
+        let disk = compute_disk_layout(data);
+        let compacted_disk = match part {
+            1 => compact_disk(&disk),
+            _ => compact_disk_2(&disk, data),
+        };
+        Some(visualize_disk(&compacted_disk))
+    }
+
+    fn generate(rng: &mut Rng, scale: f64) -> Option<Generated> {
+        Some(generate::generate(rng, scale))
+    }
+}
+
+fn compact_disk_2(disk: &[i32], disk_layout: &[usize]) -> Vec<i32> {
+    let mut compacted_disk: Vec<i32> = disk.to_vec().clone();
+
+    let mut block_map: Vec<(usize, usize, usize)> = vec![]; // (disk_index, size, block_id)
+    let mut free_space_map: Vec<(usize, usize)> = vec![]; // (disk_index, size)
+
+    let mut block_id = 0;
+    let mut disk_cursor = 0;
+    for (i, size) in disk_layout.iter().enumerate() {
+        if i % 2 == 0 {
+            block_map.push((disk_cursor, *size, block_id));
+            block_id += 1;
+        } else {
+            free_space_map.push((disk_cursor, *size));
+        }
+        disk_cursor += size;
+    }
+
+    for (block_idx, block_size, block_id) in block_map.iter().rev() {
+        for (i, (free_space_idx, free_space_size)) in free_space_map.iter().enumerate() {
+            if *free_space_size == 0 {
+                continue;
+            }
+            if free_space_idx >= block_idx {
+                break;
+            }
+
+            if *block_size > *free_space_size {
+                continue;
+            }
+
+            // copy block to compacted disk
+            let block = vec![*block_id as i32; *block_size];
+
+            compacted_disk[*free_space_idx..*free_space_idx + *block_size].copy_from_slice(&block);
+            compacted_disk[*block_idx..*block_idx + *block_size].fill(-1);
+            free_space_map[i] = (free_space_idx + *block_size, *free_space_size - *block_size);
+            break;
+        }
+    }
+
+    compacted_disk
+}
+
+fn compute_disk_layout(data: &[usize]) -> Vec<i32> {
+    let mut block_index = 0;
+    let mut current_index = 0;
+
+    let disk_size = data.iter().sum::<usize>();
+
+    let mut disk: Vec<i32> = vec![-1; disk_size]; // -1 represents free space
+    for (i, block_size) in data.iter().copied().enumerate() {
+        // Value represents block size
+        if i % 2 == 0 {
+            let block = vec![block_index; block_size];
+            disk[current_index..current_index + block_size].copy_from_slice(&block);
+            current_index += block_size;
+            block_index += 1;
+            continue;
+        }
+
+        // Value represents free space
+        let block = vec![-1; block_size];
+        disk[current_index..current_index + block_size].copy_from_slice(&block);
+        current_index += block_size;
+    }
+
+    disk
+}
+
+fn compact_disk(disk: &[i32]) -> Vec<i32> {
+    let compacted_disk_size = disk.iter().filter(|x| **x != -1).count();
+    let mut compacted_disk: Vec<i32> = vec![-1; compacted_disk_size];
+
+    let mut idx = 0;
+    let mut wrap_index = disk.len().saturating_sub(1);
+
+    loop {
+        // A disk without free space is already compacted
+        if idx == compacted_disk_size {
+            break;
+        }
+        let value = disk[idx];
+        if value != -1 {
+            compacted_disk[idx] = value;
+            idx += 1;
+            continue;
+        }
+
+        let wrap_value = disk[wrap_index];
+        if wrap_value == -1 {
+            wrap_index -= 1;
+            continue;
+        }
+
+        if idx == wrap_index {
+            break;
+        }
+        // free space
+        compacted_disk[idx] = disk[wrap_index];
+
+        idx += 1;
+        wrap_index -= 1;
+    }
+
+    compacted_disk
+}
+
+// Blocks per row when drawing a disk, which is far too long for a single line.
+const DISK_WIDTH: usize = 64;
+
+// Draws each block as the last digit of its file id, or `.` when free, wrapped into rows.
+fn visualize_disk(disk: &[i32]) -> Frame {
+    let height = disk.len().div_ceil(DISK_WIDTH);
+    let mut frame = Frame::new(&Grid::new(DISK_WIDTH, height, ' '), |c| *c);
+
+    for (i, block) in disk.iter().enumerate() {
+        let coords = Coords::new(i % DISK_WIDTH, i / DISK_WIDTH);
+        let cell = frame.cell_mut(coords);
+
+        if *block == -1 {
+            cell.glyph = '.';
+        } else {
+            cell.glyph = char::from_digit(*block as u32 % 10, 10).unwrap();
+            cell.fg = Some(Rgb::palette(*block as usize));
+        }
+    }
+
+    frame
+}
+
+fn compute_checksum(data: &[i32]) -> i64 {
+    let mut sum: i64 = 0;
+    for (i, value) in data.iter().enumerate() {
+        if *value == -1 {
+            continue;
+        }
+        sum += *value as i64 * i as i64;
+    }
+    sum
+}
+
+fn read_data(input: &str) -> Result<Vec<usize>, ParseError> {
+    let mut lines = input::numbered_lines(input);
+
+    let Some(line) = lines.next() else {
+        return Err(ParseError::at_end(input, "expected a disk map"));
+    };
+    if let Some(extra) = lines.next() {
+        return Err(extra.error(1, extra.text, "expected the disk map on a single line"));
+    }
+
+    line.chars()
+        .map(|(column, c)| input::digit(line, column, c).map(|d| d as usize))
+        .collect()
+}
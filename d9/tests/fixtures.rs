@@ -0,0 +1,18 @@
+use aoc_common::fixtures;
+use d9::Day9;
+
+#[test]
+fn answers_match_fixtures() {
+    fixtures::assert_answers::<Day9>(env!("CARGO_MANIFEST_DIR"));
+}
+
+#[test]
+fn generated_inputs_match_their_answers() {
+    fixtures::assert_generated::<Day9>(1.0);
+}
+
+#[test]
+fn tiny_generated_inputs_without_free_space_solve() {
+    // A single file, so there is no free space at all
+    fixtures::assert_generated::<Day9>(0.0001);
+}